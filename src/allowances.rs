@@ -4,12 +4,31 @@ use cosmwasm_std::{
 };
 use cw20::{AllowanceResponse, Cw20ReceiveMsg, Expiration};
 use crate::fee::{add_fee_attributes, calculate_fee};
-
 #[cfg(test)]
 use crate::fee::apply_fee_transfers;
 
+
 use crate::error::ContractError;
-use crate::state::{ALLOWANCES, ALLOWANCES_SPENDER, BALANCES, FEE_CONFIG, TOKEN_INFO};
+use crate::freeze::assert_not_frozen;
+use crate::hooks::balance_change_hooks;
+use crate::limits::{assert_transfer_limits, record_outgoing_transfer};
+use crate::memo::{add_memo_attributes, assert_memo_attached, wrap_memo_msg};
+use crate::msg::{
+    AllowanceResponse as AllowanceQueryResponse, AllowanceUpdate, IsOperatorResponse, MemoInfo,
+};
+use crate::state::{
+    ALLOWANCES, ALLOWANCES_SPENDER, ALLOWED_RECIPIENTS, BALANCES, FEE_CONFIG, OPERATORS,
+    TOKEN_INFO,
+};
+use crate::supply::record_total_supply;
+use crate::vesting::assert_unlocked;
+
+/// 무제한 허용량 값. 이 값으로 설정된 허용량은 `TransferFrom` 등에서 차감되지 않는다.
+pub const UNLIMITED_ALLOWANCE: Uint128 = Uint128::MAX;
+
+pub fn is_unlimited(allowance: &AllowanceResponse) -> bool {
+    allowance.allowance == UNLIMITED_ALLOWANCE
+}
 
 pub fn execute_increase_allowance(
     deps: DepsMut,
@@ -18,6 +37,7 @@ pub fn execute_increase_allowance(
     spender: String,
     amount: Uint128,
     expires: Option<Expiration>,
+    allowed_recipients: Option<Vec<String>>,
 ) -> Result<Response, ContractError> {
     #[cfg(test)]
     let spender_addr = Addr::unchecked(&spender);
@@ -29,6 +49,26 @@ pub fn execute_increase_allowance(
         return Err(ContractError::CannotSetOwnAccount {});
     }
 
+    // 허용 수신자 목록 갱신 (None이면 기존 설정 유지, 빈 목록이면 제한 해제)
+    if let Some(recipients) = allowed_recipients {
+        let key = (&info.sender, &spender_addr);
+        if recipients.is_empty() {
+            ALLOWED_RECIPIENTS.remove(deps.storage, key);
+        } else {
+            let recipients = recipients
+                .iter()
+                .map(|r| {
+                    #[cfg(test)]
+                    let addr = Addr::unchecked(r);
+                    #[cfg(not(test))]
+                    let addr = deps.api.addr_validate(r)?;
+                    Ok(addr)
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            ALLOWED_RECIPIENTS.save(deps.storage, key, &recipients)?;
+        }
+    }
+
     let update_fn = |allow: Option<AllowanceResponse>| -> Result<_, _> {
         let mut val = allow.unwrap_or_default();
         if let Some(exp) = expires {
@@ -37,10 +77,20 @@ pub fn execute_increase_allowance(
             }
             val.expires = exp;
         }
-        val.allowance += amount;
+        // Uint128::MAX를 직접 요청한 경우에만 무제한. 합산 결과가 한도에 닿으면 거부
+        if amount == UNLIMITED_ALLOWANCE || val.allowance == UNLIMITED_ALLOWANCE {
+            val.allowance = UNLIMITED_ALLOWANCE;
+        } else {
+            val.allowance = val
+                .allowance
+                .checked_add(amount)
+                .ok()
+                .filter(|allowance| *allowance != UNLIMITED_ALLOWANCE)
+                .ok_or(ContractError::AllowanceOverflow {})?;
+        }
         Ok(val)
     };
-    ALLOWANCES.update(deps.storage, (&info.sender, &spender_addr), update_fn)?;
+    let allowance = ALLOWANCES.update(deps.storage, (&info.sender, &spender_addr), update_fn)?;
     ALLOWANCES_SPENDER.update(deps.storage, (&spender_addr, &info.sender), update_fn)?;
 
     let res = Response::new().add_attributes(vec![
@@ -48,6 +98,7 @@ pub fn execute_increase_allowance(
         attr("owner", info.sender),
         attr("spender", spender),
         attr("amount", amount),
+        attr("unlimited", is_unlimited(&allowance).to_string()),
     ]);
     Ok(res)
 }
@@ -76,7 +127,8 @@ pub fn execute_decrease_allowance(
         (t.1, t.0)
     }
 
-    // load value and delete if it hits 0, or update otherwise
+    // load value and delete if it hits 0, or update otherwise.
+    // 무제한 허용량에서 차감하면 일반(유한) 허용량으로 전환된다.
     let mut allowance = ALLOWANCES.load(deps.storage, key)?;
     if amount < allowance.allowance {
         // update the new amount
@@ -95,6 +147,7 @@ pub fn execute_decrease_allowance(
     } else {
         ALLOWANCES.remove(deps.storage, key);
         ALLOWANCES_SPENDER.remove(deps.storage, reverse(key));
+        ALLOWED_RECIPIENTS.remove(deps.storage, key);
     }
 
     let res = Response::new().add_attributes(vec![
@@ -106,6 +159,114 @@ pub fn execute_decrease_allowance(
     Ok(res)
 }
 
+pub fn execute_set_allowance(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    spender: String,
+    amount: Uint128,
+    expires: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    #[cfg(test)]
+    let spender_addr = Addr::unchecked(&spender);
+    
+    #[cfg(not(test))]
+    let spender_addr = deps.api.addr_validate(&spender)?;
+
+    if spender_addr == info.sender {
+        return Err(ContractError::CannotSetOwnAccount {});
+    }
+
+    let key = (&info.sender, &spender_addr);
+    if amount.is_zero() {
+        ALLOWANCES.remove(deps.storage, key);
+        ALLOWANCES_SPENDER.remove(deps.storage, (&spender_addr, &info.sender));
+        ALLOWED_RECIPIENTS.remove(deps.storage, key);
+    } else {
+        // 만료가 지정되지 않으면 기존 만료 유지
+        let mut allowance = ALLOWANCES.may_load(deps.storage, key)?.unwrap_or_default();
+        if let Some(exp) = expires {
+            if exp.is_expired(&env.block) {
+                return Err(ContractError::InvalidExpiration {});
+            }
+            allowance.expires = exp;
+        }
+        allowance.allowance = amount;
+        ALLOWANCES.save(deps.storage, key, &allowance)?;
+        ALLOWANCES_SPENDER.save(deps.storage, (&spender_addr, &info.sender), &allowance)?;
+    }
+
+    let res = Response::new().add_attributes(vec![
+        attr("action", "set_allowance"),
+        attr("owner", info.sender),
+        attr("spender", spender),
+        attr("amount", amount),
+    ]);
+    Ok(res)
+}
+
+// 각 항목을 기존 허용량 함수로 순서대로 적용. 오류가 나면 트랜잭션 전체가 되돌려진다
+pub fn execute_batch_allowances(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    updates: Vec<AllowanceUpdate>,
+) -> Result<Response, ContractError> {
+    if updates.is_empty() {
+        return Err(ContractError::EmptyBatch {});
+    }
+
+    let mut res = Response::new().add_attributes(vec![
+        attr("action", "batch_allowances"),
+        attr("owner", info.sender.to_string()),
+        attr("count", updates.len().to_string()),
+    ]);
+    for update in updates {
+        let sub = match update {
+            AllowanceUpdate::Increase {
+                spender,
+                amount,
+                expires,
+                allowed_recipients,
+            } => execute_increase_allowance(
+                deps.branch(),
+                env.clone(),
+                info.clone(),
+                spender,
+                amount,
+                expires,
+                allowed_recipients,
+            )?,
+            AllowanceUpdate::Decrease {
+                spender,
+                amount,
+                expires,
+            } => execute_decrease_allowance(
+                deps.branch(),
+                env.clone(),
+                info.clone(),
+                spender,
+                amount,
+                expires,
+            )?,
+            AllowanceUpdate::Set {
+                spender,
+                amount,
+                expires,
+            } => execute_set_allowance(
+                deps.branch(),
+                env.clone(),
+                info.clone(),
+                spender,
+                amount,
+                expires,
+            )?,
+        };
+        res = res.add_attributes(sub.attributes);
+    }
+    Ok(res)
+}
+
 // this can be used to update a lower allowance - call bucket.update with proper keys
 pub fn deduct_allowance(
     storage: &mut dyn Storage,
@@ -114,6 +275,16 @@ pub fn deduct_allowance(
     block: &BlockInfo,
     amount: Uint128,
 ) -> Result<AllowanceResponse, ContractError> {
+    // 무제한 허용량은 만료만 확인하고 저장소를 다시 쓰지 않는다
+    if let Some(current) = ALLOWANCES.may_load(storage, (owner, spender))? {
+        if is_unlimited(&current) {
+            if current.expires.is_expired(block) {
+                return Err(ContractError::Expired {});
+            }
+            return Ok(current);
+        }
+    }
+
     let update_fn = |current: Option<AllowanceResponse>| -> _ {
         match current {
             Some(mut a) => {
@@ -135,6 +306,102 @@ pub fn deduct_allowance(
     ALLOWANCES_SPENDER.update(storage, (spender, owner), update_fn)
 }
 
+pub fn execute_approve_operator(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    operator: String,
+    expires: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    #[cfg(test)]
+    let operator_addr = Addr::unchecked(&operator);
+
+    #[cfg(not(test))]
+    let operator_addr = deps.api.addr_validate(&operator)?;
+
+    if operator_addr == info.sender {
+        return Err(ContractError::CannotSetOwnAccount {});
+    }
+
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::InvalidExpiration {});
+    }
+    OPERATORS.save(deps.storage, (&info.sender, &operator_addr), &expires)?;
+
+    let res = Response::new().add_attributes(vec![
+        attr("action", "approve_operator"),
+        attr("owner", info.sender),
+        attr("operator", operator),
+        attr("expires", expires.to_string()),
+    ]);
+    Ok(res)
+}
+
+pub fn execute_revoke_operator(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    operator: String,
+) -> Result<Response, ContractError> {
+    #[cfg(test)]
+    let operator_addr = Addr::unchecked(&operator);
+
+    #[cfg(not(test))]
+    let operator_addr = deps.api.addr_validate(&operator)?;
+
+    if !OPERATORS.has(deps.storage, (&info.sender, &operator_addr)) {
+        return Err(ContractError::NoAllowance {});
+    }
+    OPERATORS.remove(deps.storage, (&info.sender, &operator_addr));
+
+    let res = Response::new().add_attributes(vec![
+        attr("action", "revoke_operator"),
+        attr("owner", info.sender),
+        attr("operator", operator),
+    ]);
+    Ok(res)
+}
+
+// 만료되지 않은 운영자 승인이 있는지 확인
+pub fn is_operator(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    owner: &Addr,
+    operator: &Addr,
+) -> StdResult<bool> {
+    Ok(OPERATORS
+        .may_load(storage, (owner, operator))?
+        .is_some_and(|expires| !expires.is_expired(block)))
+}
+
+// 허용량에 수신자 제한이 있으면 recipient가 목록에 포함되어 있는지 확인
+pub fn assert_recipient_allowed(
+    storage: &dyn Storage,
+    owner: &Addr,
+    spender: &Addr,
+    recipient: &Addr,
+) -> Result<(), ContractError> {
+    match ALLOWED_RECIPIENTS.may_load(storage, (owner, spender))? {
+        Some(recipients) if !recipients.contains(recipient) => {
+            Err(ContractError::RecipientNotAllowed {
+                recipient: recipient.to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn query_allowed_recipients(
+    storage: &dyn Storage,
+    owner: &Addr,
+    spender: &Addr,
+) -> StdResult<Option<Vec<String>>> {
+    Ok(ALLOWED_RECIPIENTS
+        .may_load(storage, (owner, spender))?
+        .map(|recipients| recipients.into_iter().map(Into::into).collect()))
+}
+
 pub fn execute_transfer_from(
     deps: DepsMut,
     env: Env,
@@ -142,6 +409,7 @@ pub fn execute_transfer_from(
     owner: String,
     recipient: String,
     amount: Uint128,
+    memo: Option<MemoInfo>,
 ) -> Result<Response, ContractError> {
     // 주소 검증 (기존 코드 유지)
     #[cfg(test)]
@@ -154,31 +422,56 @@ pub fn execute_transfer_from(
     #[cfg(not(test))]
     let owner_addr = deps.api.addr_validate(&owner)?;
 
-    // 허용량 차감 먼저 수행
-    deduct_allowance(deps.storage, &owner_addr, &info.sender, &env.block, amount)?;
+    assert_not_frozen(deps.storage, &[&owner_addr, &info.sender, &rcpt_addr])?;
+    if let Some(memo) = &memo {
+        memo.validate()?;
+    }
+    assert_memo_attached(deps.storage, &rcpt_addr, memo.as_ref())?;
+
+    // 허용량 차감 먼저 수행 (운영자는 허용량 없이 전송 가능)
+    if !is_operator(deps.storage, &env.block, &owner_addr, &info.sender)? {
+        assert_recipient_allowed(deps.storage, &owner_addr, &info.sender, &rcpt_addr)?;
+        deduct_allowance(deps.storage, &owner_addr, &info.sender, &env.block, amount)?;
+    }
 
     // 수수료 계산 (새로운 방식)
     let fee_result = calculate_fee(deps.as_ref(), amount, &owner_addr)?;
+    assert_transfer_limits(
+        deps.storage,
+        &owner_addr,
+        &rcpt_addr,
+        amount,
+        fee_result.transfer_amount,
+    )?;
+    record_outgoing_transfer(deps.storage, &env.block, &owner_addr)?;
     
-    // 발신자 잔액 감소
-    BALANCES.update(
+    // 발신자 잔액 감소 (베스팅 잠금 확인)
+    let owner_balance = BALANCES.update(
         deps.storage,
         &owner_addr,
         |balance: Option<Uint128>| -> StdResult<_> {
             Ok(balance.unwrap_or_default().checked_sub(amount)?)
         },
     )?;
+    assert_unlocked(deps.storage, &env.block, &owner_addr, owner_balance)?;
     
     // 수신자 잔액 증가 (수수료 차감 후)
-    BALANCES.update(
+    let rcpt_balance = BALANCES.update(
         deps.storage,
         &rcpt_addr,
         |balance: Option<Uint128>| -> StdResult<_> { 
             Ok(balance.unwrap_or_default() + fee_result.transfer_amount) 
         },
     )?;
+    let hook_msgs = balance_change_hooks(
+        deps.storage,
+        vec![
+            (owner_addr, owner_balance + amount, owner_balance),
+            (rcpt_addr, rcpt_balance - fee_result.transfer_amount, rcpt_balance),
+        ],
+    )?;
 
-     // 테스트 환경에서만 수수료 이체 직접 처리
+    // 테스트 환경에서만 수수료 이체 직접 처리
     #[cfg(test)]
     apply_fee_transfers(deps.storage, &fee_result)?;
     
@@ -188,7 +481,9 @@ pub fn execute_transfer_from(
         .add_attribute("from", owner)
         .add_attribute("to", recipient)
         .add_attribute("by", info.sender)
-        .add_attribute("amount", fee_result.transfer_amount);
+        .add_attribute("amount", fee_result.transfer_amount)
+        .add_submessages(hook_msgs);
+    response = add_memo_attributes(response, memo.as_ref());
     
     // 수수료 메시지가 있으면 추가 (클론을 사용하여 소유권 이동 방지)
     if !fee_result.fee_msgs.is_empty() {
@@ -211,33 +506,50 @@ pub fn execute_burn_from(
     amount: Uint128,
 ) -> Result<Response, ContractError> {
     let owner_addr = deps.api.addr_validate(&owner)?;
+    assert_not_frozen(deps.storage, &[&owner_addr, &info.sender])?;
 
     // deduct allowance before doing anything else have enough allowance
-    deduct_allowance(deps.storage, &owner_addr, &info.sender, &env.block, amount)?;
+    // (수신자 제한이 있는 허용량으로는 소각 불가)
+    if !is_operator(deps.storage, &env.block, &owner_addr, &info.sender)? {
+        if ALLOWED_RECIPIENTS.has(deps.storage, (&owner_addr, &info.sender)) {
+            return Err(ContractError::BurnNotAllowed {});
+        }
+        deduct_allowance(deps.storage, &owner_addr, &info.sender, &env.block, amount)?;
+    }
 
     // lower balance
-    BALANCES.update(
+    let owner_balance = BALANCES.update(
         deps.storage,
         &owner_addr,
         |balance: Option<Uint128>| -> StdResult<_> {
             Ok(balance.unwrap_or_default().checked_sub(amount)?)
         },
     )?;
+    assert_unlocked(deps.storage, &env.block, &owner_addr, owner_balance)?;
     // reduce total_supply
-    TOKEN_INFO.update(deps.storage, |mut meta| -> StdResult<_> {
+    let token_info = TOKEN_INFO.update(deps.storage, |mut meta| -> StdResult<_> {
         meta.total_supply = meta.total_supply.checked_sub(amount)?;
         Ok(meta)
     })?;
+    record_total_supply(deps.storage, env.block.height, token_info.total_supply)?;
 
-    let res = Response::new().add_attributes(vec![
-        attr("action", "burn_from"),
-        attr("from", owner),
-        attr("by", info.sender),
-        attr("amount", amount),
-    ]);
+    let hook_msgs = balance_change_hooks(
+        deps.storage,
+        vec![(owner_addr, owner_balance + amount, owner_balance)],
+    )?;
+
+    let res = Response::new()
+        .add_attributes(vec![
+            attr("action", "burn_from"),
+            attr("from", owner),
+            attr("by", info.sender),
+            attr("amount", amount),
+        ])
+        .add_submessages(hook_msgs);
     Ok(res)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn execute_send_from(
     deps: DepsMut,
     env: Env,
@@ -246,6 +558,7 @@ pub fn execute_send_from(
     contract: String,
     amount: Uint128,
     msg: Binary,
+    memo: Option<MemoInfo>,
 ) -> Result<Response, ContractError> {
     #[cfg(test)]
     let rcpt_addr = Addr::unchecked(&contract);
@@ -257,31 +570,56 @@ pub fn execute_send_from(
     #[cfg(not(test))]
     let owner_addr = deps.api.addr_validate(&owner)?;
 
-    // 허용량 차감 먼저 수행
-    deduct_allowance(deps.storage, &owner_addr, &info.sender, &env.block, amount)?;
+    assert_not_frozen(deps.storage, &[&owner_addr, &info.sender, &rcpt_addr])?;
+
+    // 메모가 있으면 수신 컨트랙트가 받을 메시지에 함께 담아 전달
+    let msg = wrap_memo_msg(msg, memo.as_ref())?;
+    assert_memo_attached(deps.storage, &rcpt_addr, memo.as_ref())?;
+
+    // 허용량 차감 먼저 수행 (운영자는 허용량 없이 전송 가능)
+    if !is_operator(deps.storage, &env.block, &owner_addr, &info.sender)? {
+        assert_recipient_allowed(deps.storage, &owner_addr, &info.sender, &rcpt_addr)?;
+        deduct_allowance(deps.storage, &owner_addr, &info.sender, &env.block, amount)?;
+    }
 
     // 수수료 계산 (새로운 방식)
     let fee_result = calculate_fee(deps.as_ref(), amount, &owner_addr)?;
+    assert_transfer_limits(
+        deps.storage,
+        &owner_addr,
+        &rcpt_addr,
+        amount,
+        fee_result.transfer_amount,
+    )?;
+    record_outgoing_transfer(deps.storage, &env.block, &owner_addr)?;
     
-    // 발신자 잔액 감소
-    BALANCES.update(
+    // 발신자 잔액 감소 (베스팅 잠금 확인)
+    let owner_balance = BALANCES.update(
         deps.storage,
         &owner_addr,
         |balance: Option<Uint128>| -> StdResult<_> {
             Ok(balance.unwrap_or_default().checked_sub(amount)?)
         },
     )?;
+    assert_unlocked(deps.storage, &env.block, &owner_addr, owner_balance)?;
     
     // 수신자 잔액 증가 (수수료 차감 후)
-    BALANCES.update(
+    let rcpt_balance = BALANCES.update(
         deps.storage,
         &rcpt_addr,
         |balance: Option<Uint128>| -> StdResult<_> { 
             Ok(balance.unwrap_or_default() + fee_result.transfer_amount) 
         },
     )?;
+    let hook_msgs = balance_change_hooks(
+        deps.storage,
+        vec![
+            (owner_addr, owner_balance + amount, owner_balance),
+            (rcpt_addr, rcpt_balance - fee_result.transfer_amount, rcpt_balance),
+        ],
+    )?;
 
-     // 테스트 환경에서만 수수료 이체 직접 처리
+    // 테스트 환경에서만 수수료 이체 직접 처리
     #[cfg(test)]
     apply_fee_transfers(deps.storage, &fee_result)?;
     
@@ -291,7 +629,9 @@ pub fn execute_send_from(
         .add_attribute("from", owner)
         .add_attribute("to", contract.clone())
         .add_attribute("by", info.sender.to_string())
-        .add_attribute("amount", fee_result.transfer_amount);
+        .add_attribute("amount", fee_result.transfer_amount)
+        .add_submessages(hook_msgs);
+    response = add_memo_attributes(response, memo.as_ref());
 
     // 수수료 메시지가 있으면 추가 (클론을 사용하여 소유권 이동 방지)
     if !fee_result.fee_msgs.is_empty() {
@@ -315,7 +655,11 @@ pub fn execute_send_from(
     Ok(response)
 }
 
-pub fn query_allowance(deps: Deps, owner: String, spender: String) -> StdResult<AllowanceResponse> {
+pub fn query_allowance(
+    deps: Deps,
+    owner: String,
+    spender: String,
+) -> StdResult<AllowanceQueryResponse> {
     #[cfg(test)]
     let owner_addr = Addr::unchecked(&owner);
     #[cfg(not(test))]
@@ -329,7 +673,32 @@ pub fn query_allowance(deps: Deps, owner: String, spender: String) -> StdResult<
     let allowance = ALLOWANCES
         .may_load(deps.storage, (&owner_addr, &spender_addr))?
         .unwrap_or_default();
-    Ok(allowance)
+    Ok(AllowanceQueryResponse {
+        allowance: allowance.allowance,
+        expires: allowance.expires,
+        allowed_recipients: query_allowed_recipients(deps.storage, &owner_addr, &spender_addr)?,
+    })
+}
+
+pub fn query_is_operator(
+    deps: Deps,
+    env: Env,
+    owner: String,
+    operator: String,
+) -> StdResult<IsOperatorResponse> {
+    #[cfg(test)]
+    let owner_addr = Addr::unchecked(&owner);
+    #[cfg(not(test))]
+    let owner_addr = deps.api.addr_validate(&owner)?;
+
+    #[cfg(test)]
+    let operator_addr = Addr::unchecked(&operator);
+    #[cfg(not(test))]
+    let operator_addr = deps.api.addr_validate(&operator)?;
+
+    Ok(IsOperatorResponse {
+        is_operator: is_operator(deps.storage, &env.block, &owner_addr, &operator_addr)?,
+    })
 }
 
 #[cfg(test)]
@@ -337,10 +706,10 @@ mod tests {
     use super::*;
     use cosmwasm_std::testing::{mock_dependencies, mock_env};
     use cosmwasm_std::{ Coin, CosmosMsg, SubMsg, Timestamp, WasmMsg};
-    use cw20::{Cw20Coin, TokenInfoResponse};
+    use cw20::TokenInfoResponse;
 
     use crate::contract::{execute, instantiate, query_balance, query_token_info};
-    use crate::msg::{ExecuteMsg, InstantiateMsg};
+    use crate::msg::{AllowanceResponse, ExecuteMsg, InitialBalance, InstantiateMsg};
 
     fn get_balance<T: Into<String>>(deps: Deps, address: T) -> Uint128 {
         query_balance(deps, address.into()).unwrap().balance
@@ -364,9 +733,10 @@ mod tests {
             name: "Auto Gen".to_string(),
             symbol: "AUTO".to_string(),
             decimals: 3,
-            initial_balances: vec![Cw20Coin {
+            initial_balances: vec![InitialBalance {
                 address: addr.into(),
                 amount,
+                vesting: None,
             }],
             mint: None,
             marketing: None,
@@ -399,6 +769,7 @@ mod tests {
             spender: spender.clone(),
             amount: allow1,
             expires: Some(expires),
+            allowed_recipients: None,
         };
         execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
 
@@ -408,7 +779,8 @@ mod tests {
             allowance,
             AllowanceResponse {
                 allowance: allow1,
-                expires
+                expires,
+                allowed_recipients: None,
             }
         );
 
@@ -426,7 +798,8 @@ mod tests {
             allowance,
             AllowanceResponse {
                 allowance: allow2,
-                expires
+                expires,
+                allowed_recipients: None,
             }
         );
 
@@ -438,6 +811,7 @@ mod tests {
             spender: spender.clone(),
             amount: raise,
             expires: Some(new_expire),
+            allowed_recipients: None,
         };
         execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
         let allowance = query_allowance(deps.as_ref(), owner.clone(), spender.clone()).unwrap();
@@ -445,7 +819,8 @@ mod tests {
             allowance,
             AllowanceResponse {
                 allowance: allow3,
-                expires: new_expire
+                expires: new_expire,
+                allowed_recipients: None,
             }
         );
 
@@ -492,6 +867,7 @@ mod tests {
             spender: spender.clone(),
             amount: allow1,
             expires: Some(expires),
+            allowed_recipients: None,
         };
         execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
 
@@ -501,6 +877,7 @@ mod tests {
             spender: spender2.clone(),
             amount: allow2,
             expires: None,
+            allowed_recipients: None,
         };
         execute(deps.as_mut(), env, info, msg).unwrap();
 
@@ -508,10 +885,12 @@ mod tests {
         let expect_one = AllowanceResponse {
             allowance: allow1,
             expires,
+            allowed_recipients: None,
         };
         let expect_two = AllowanceResponse {
             allowance: allow2,
             expires: Expiration::Never {},
+            allowed_recipients: None,
         };
         assert_eq!(
             query_allowance(deps.as_ref(), owner.clone(), spender.clone()).unwrap(),
@@ -535,11 +914,13 @@ mod tests {
             spender: spender2.clone(),
             amount: allow3,
             expires: Some(expires3),
+            allowed_recipients: None,
         };
         execute(deps.as_mut(), env, info, msg).unwrap();
         let expect_three = AllowanceResponse {
             allowance: allow3,
             expires: expires3,
+            allowed_recipients: None,
         };
         assert_eq!(
             query_allowance(deps.as_ref(), owner.clone(), spender.clone()).unwrap(),
@@ -569,6 +950,7 @@ mod tests {
             spender: owner.clone(),
             amount: Uint128::new(7777),
             expires: None,
+            allowed_recipients: None,
         };
         let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
         assert_eq!(err, ContractError::CannotSetOwnAccount {});
@@ -599,6 +981,7 @@ mod tests {
             spender: spender.clone(),
             amount: allow1,
             expires: None,
+            allowed_recipients: None,
         };
         let info = create_message_info(owner.as_ref(), vec![]);
         let env = mock_env();
@@ -628,6 +1011,7 @@ mod tests {
         let expect = AllowanceResponse {
             allowance: allow1.checked_sub(transfer).unwrap(),
             expires: Expiration::Never {},
+            allowed_recipients: None,
         };
         assert_eq!(expect, allowance);
 
@@ -649,6 +1033,7 @@ mod tests {
             spender: spender.clone(),
             amount: Uint128::new(1000),
             expires: Some(Expiration::AtHeight(env.block.height + 1)),
+            allowed_recipients: None,
         };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
@@ -680,6 +1065,7 @@ mod tests {
             spender: spender.clone(),
             amount: allow1,
             expires: None,
+            allowed_recipients: None,
         };
         let info = create_message_info(owner.as_ref(), vec![]);
         let env = mock_env();
@@ -707,6 +1093,7 @@ mod tests {
         let expect = AllowanceResponse {
             allowance: allow1.checked_sub(transfer).unwrap(),
             expires: Expiration::Never {},
+            allowed_recipients: None,
         };
         assert_eq!(expect, allowance);
 
@@ -727,6 +1114,7 @@ mod tests {
             spender: spender.clone(),
             amount: Uint128::new(1000),
             expires: Some(Expiration::AtHeight(env.block.height + 1)),
+            allowed_recipients: None,
         };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
@@ -760,6 +1148,7 @@ mod tests {
             spender: spender.clone(),
             amount: allow1,
             expires: None,
+            allowed_recipients: None,
         };
         let info = create_message_info(owner.as_ref(), vec![]);
         let env = mock_env();
@@ -808,6 +1197,7 @@ mod tests {
         let expect = AllowanceResponse {
             allowance: allow1.checked_sub(transfer).unwrap(),
             expires: Expiration::Never {},
+            allowed_recipients: None,
         };
         assert_eq!(expect, allowance);
 
@@ -830,6 +1220,7 @@ mod tests {
             spender: spender.clone(),
             amount: Uint128::new(1000),
             expires: Some(Expiration::AtHeight(env.block.height + 1)),
+            allowed_recipients: None,
         };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
@@ -864,6 +1255,7 @@ mod tests {
             spender: spender.clone(),
             amount: Uint128::new(7777),
             expires: Some(expires),
+            allowed_recipients: None,
         };
 
         // ensure it is rejected
@@ -878,6 +1270,7 @@ mod tests {
             spender: spender.clone(),
             amount: Uint128::new(7777),
             expires: Some(expires),
+            allowed_recipients: None,
         };
 
         // ensure it is rejected
@@ -893,6 +1286,7 @@ mod tests {
             spender: spender.clone(),
             amount: allow,
             expires: Some(expires),
+            allowed_recipients: None,
         };
 
         execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
@@ -903,7 +1297,8 @@ mod tests {
             allowance,
             AllowanceResponse {
                 allowance: allow,
-                expires
+                expires,
+                allowed_recipients: None,
             }
         );
 
@@ -914,6 +1309,7 @@ mod tests {
             spender: spender.clone(),
             amount: allow,
             expires: Some(expires),
+            allowed_recipients: None,
         };
 
         execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
@@ -924,7 +1320,8 @@ mod tests {
             allowance,
             AllowanceResponse {
                 allowance: allow + allow, // we increased twice
-                expires
+                expires,
+                allowed_recipients: None,
             }
         );
 
@@ -935,6 +1332,7 @@ mod tests {
             spender: spender.clone(),
             amount: allow,
             expires: Some(expires),
+            allowed_recipients: None,
         };
 
         // ensure it is rejected
@@ -960,8 +1358,358 @@ mod tests {
             allowance,
             AllowanceResponse {
                 allowance: allow,
-                expires
+                expires,
+                allowed_recipients: None,
             }
         );
     }
+
+    #[test]
+    fn unlimited_allowance_is_not_decremented() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("addr0001").to_string();
+        let spender = deps.api.addr_make("addr0002").to_string();
+        let rcpt = deps.api.addr_make("addr0003").to_string();
+
+        let start = Uint128::new(999999);
+        do_instantiate(deps.as_mut(), &owner, start);
+
+        // provide an unlimited allowance
+        let msg = ExecuteMsg::IncreaseAllowance {
+            spender: spender.clone(),
+            amount: UNLIMITED_ALLOWANCE,
+            expires: None,
+            allowed_recipients: None,
+        };
+        let info = create_message_info(owner.as_ref(), vec![]);
+        let env = mock_env();
+        let res = execute(deps.as_mut(), env, info, msg).unwrap();
+        assert_eq!(res.attributes[4], attr("unlimited", "true"));
+
+        // transfer twice, the allowance stays untouched
+        for _ in 0..2 {
+            let msg = ExecuteMsg::TransferFrom {
+                owner: owner.clone(),
+                recipient: rcpt.clone(),
+                amount: Uint128::new(1000),
+            };
+            let info = create_message_info(spender.as_ref(), vec![]);
+            execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        }
+        assert_eq!(get_balance(deps.as_ref(), rcpt.clone()), Uint128::new(2000));
+        let allowance = query_allowance(deps.as_ref(), owner.clone(), spender.clone()).unwrap();
+        assert_eq!(
+            allowance,
+            AllowanceResponse {
+                allowance: UNLIMITED_ALLOWANCE,
+                expires: Expiration::Never {},
+                allowed_recipients: None,
+            }
+        );
+
+        // increasing an unlimited allowance keeps it unlimited
+        let msg = ExecuteMsg::IncreaseAllowance {
+            spender: spender.clone(),
+            amount: Uint128::new(1),
+            expires: None,
+            allowed_recipients: None,
+        };
+        let info = create_message_info(owner.as_ref(), vec![]);
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+        let allowance = query_allowance(deps.as_ref(), owner.clone(), spender.clone()).unwrap();
+        assert_eq!(allowance.allowance, UNLIMITED_ALLOWANCE);
+
+        // decreasing turns it into a regular allowance which is deducted again
+        let msg = ExecuteMsg::DecreaseAllowance {
+            spender: spender.clone(),
+            amount: UNLIMITED_ALLOWANCE.checked_sub(Uint128::new(5000)).unwrap(),
+            expires: None,
+        };
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        let msg = ExecuteMsg::TransferFrom {
+            owner: owner.clone(),
+            recipient: rcpt,
+            amount: Uint128::new(1000),
+        };
+        let info = create_message_info(spender.as_ref(), vec![]);
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        let allowance = query_allowance(deps.as_ref(), owner.clone(), spender.clone()).unwrap();
+        assert_eq!(allowance.allowance, Uint128::new(4000));
+
+        // a finite allowance never overflows into an unlimited one
+        let info = create_message_info(owner.as_ref(), vec![]);
+        for amount in [
+            UNLIMITED_ALLOWANCE.checked_sub(Uint128::new(4000)).unwrap(),
+            UNLIMITED_ALLOWANCE.checked_sub(Uint128::new(1)).unwrap(),
+        ] {
+            let msg = ExecuteMsg::IncreaseAllowance {
+                spender: spender.clone(),
+                amount,
+                expires: None,
+                allowed_recipients: None,
+            };
+            let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+            assert_eq!(err, ContractError::AllowanceOverflow {});
+        }
+        let allowance = query_allowance(deps.as_ref(), owner, spender).unwrap();
+        assert_eq!(allowance.allowance, Uint128::new(4000));
+    }
+
+    #[test]
+    fn allowance_restricted_to_recipients() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("addr0001").to_string();
+        let spender = deps.api.addr_make("addr0002").to_string();
+        let shop = deps.api.addr_make("shop").to_string();
+        let other = deps.api.addr_make("other").to_string();
+
+        do_instantiate(deps.as_mut(), &owner, Uint128::new(999999));
+
+        // allowance bound to the shop only
+        let msg = ExecuteMsg::IncreaseAllowance {
+            spender: spender.clone(),
+            amount: Uint128::new(5000),
+            expires: None,
+            allowed_recipients: Some(vec![shop.clone()]),
+        };
+        let info = create_message_info(owner.as_ref(), vec![]);
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+        let allowance = query_allowance(deps.as_ref(), owner.clone(), spender.clone()).unwrap();
+        assert_eq!(allowance.allowed_recipients, Some(vec![shop.clone()]));
+
+        // transfer or send anywhere else fails
+        let spender_info = create_message_info(spender.as_ref(), vec![]);
+        let msg = ExecuteMsg::TransferFrom {
+            owner: owner.clone(),
+            recipient: other.clone(),
+            amount: Uint128::new(1000),
+        };
+        let err = execute(deps.as_mut(), mock_env(), spender_info.clone(), msg).unwrap_err();
+        assert_eq!(err, ContractError::RecipientNotAllowed { recipient: other.clone() });
+        let msg = ExecuteMsg::SendFrom {
+            owner: owner.clone(),
+            contract: other.clone(),
+            amount: Uint128::new(1000),
+            msg: Binary::default(),
+        };
+        let err = execute(deps.as_mut(), mock_env(), spender_info.clone(), msg).unwrap_err();
+        assert_eq!(err, ContractError::RecipientNotAllowed { recipient: other.clone() });
+
+        // burning has no recipient, so a restricted allowance cannot burn
+        let msg = ExecuteMsg::BurnFrom {
+            owner: owner.clone(),
+            amount: Uint128::new(1000),
+        };
+        let err = execute(deps.as_mut(), mock_env(), spender_info.clone(), msg).unwrap_err();
+        assert_eq!(err, ContractError::BurnNotAllowed {});
+
+        // the shop is fine
+        let msg = ExecuteMsg::TransferFrom {
+            owner: owner.clone(),
+            recipient: shop.clone(),
+            amount: Uint128::new(1000),
+        };
+        execute(deps.as_mut(), mock_env(), spender_info.clone(), msg).unwrap();
+        assert_eq!(get_balance(deps.as_ref(), shop), Uint128::new(1000));
+
+        // an empty list lifts the restriction
+        let msg = ExecuteMsg::IncreaseAllowance {
+            spender: spender.clone(),
+            amount: Uint128::zero(),
+            expires: None,
+            allowed_recipients: Some(vec![]),
+        };
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        let allowance = query_allowance(deps.as_ref(), owner.clone(), spender).unwrap();
+        assert_eq!(allowance.allowed_recipients, None);
+        let msg = ExecuteMsg::TransferFrom {
+            owner,
+            recipient: other.clone(),
+            amount: Uint128::new(1000),
+        };
+        execute(deps.as_mut(), mock_env(), spender_info, msg).unwrap();
+        assert_eq!(get_balance(deps.as_ref(), other), Uint128::new(1000));
+    }
+
+    #[test]
+    fn operator_moves_funds_without_allowance() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("addr0001").to_string();
+        let operator = deps.api.addr_make("addr0002").to_string();
+        let rcpt = deps.api.addr_make("addr0003").to_string();
+
+        let start = Uint128::new(999999);
+        do_instantiate(deps.as_mut(), &owner, start);
+
+        // approve an operator that expires in the next block
+        let mut env = mock_env();
+        let msg = ExecuteMsg::ApproveOperator {
+            operator: operator.clone(),
+            expires: Some(Expiration::AtHeight(env.block.height + 1)),
+        };
+        let info = create_message_info(owner.as_ref(), vec![]);
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        assert!(
+            query_is_operator(deps.as_ref(), env.clone(), owner.clone(), operator.clone())
+                .unwrap()
+                .is_operator
+        );
+        let operators =
+            crate::enumerable::query_operators(deps.as_ref(), env.clone(), owner.clone(), None, None)
+                .unwrap();
+        assert_eq!(operators.operators.len(), 1);
+        assert_eq!(operators.operators[0].operator, operator);
+
+        // transfer and burn with no numeric allowance
+        let op_info = create_message_info(operator.as_ref(), vec![]);
+        let msg = ExecuteMsg::TransferFrom {
+            owner: owner.clone(),
+            recipient: rcpt.clone(),
+            amount: Uint128::new(300000),
+        };
+        execute(deps.as_mut(), env.clone(), op_info.clone(), msg).unwrap();
+        let msg = ExecuteMsg::BurnFrom {
+            owner: owner.clone(),
+            amount: Uint128::new(100000),
+        };
+        execute(deps.as_mut(), env.clone(), op_info.clone(), msg).unwrap();
+        assert_eq!(get_balance(deps.as_ref(), owner.clone()), Uint128::new(599999));
+        assert_eq!(get_balance(deps.as_ref(), rcpt.clone()), Uint128::new(300000));
+        assert_eq!(
+            query_allowance(deps.as_ref(), owner.clone(), operator.clone()).unwrap(),
+            AllowanceResponse::default()
+        );
+
+        // once expired the operator falls back to the (missing) allowance
+        env.block.height += 1;
+        let msg = ExecuteMsg::TransferFrom {
+            owner: owner.clone(),
+            recipient: rcpt.clone(),
+            amount: Uint128::new(1),
+        };
+        let err = execute(deps.as_mut(), env.clone(), op_info.clone(), msg).unwrap_err();
+        assert_eq!(err, ContractError::NoAllowance {});
+        assert!(
+            !query_is_operator(deps.as_ref(), env.clone(), owner.clone(), operator.clone())
+                .unwrap()
+                .is_operator
+        );
+
+        // approve again and revoke
+        let msg = ExecuteMsg::ApproveOperator {
+            operator: operator.clone(),
+            expires: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        let msg = ExecuteMsg::RevokeOperator {
+            operator: operator.clone(),
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        let msg = ExecuteMsg::TransferFrom {
+            owner,
+            recipient: rcpt,
+            amount: Uint128::new(1),
+        };
+        let err = execute(deps.as_mut(), env, op_info, msg).unwrap_err();
+        assert_eq!(err, ContractError::NoAllowance {});
+    }
+
+    #[test]
+    fn batch_allowances_apply_each_update() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("addr0001").to_string();
+        let game1 = deps.api.addr_make("game1").to_string();
+        let game2 = deps.api.addr_make("game2").to_string();
+        let game3 = deps.api.addr_make("game3").to_string();
+        let info = create_message_info(owner.as_ref(), vec![]);
+        let env = mock_env();
+        do_instantiate(deps.as_mut(), &owner, Uint128::new(12340000));
+
+        // give game2 an allowance to decrease later
+        let msg = ExecuteMsg::IncreaseAllowance {
+            spender: game2.clone(),
+            amount: Uint128::new(5000),
+            expires: None,
+            allowed_recipients: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+        let expires = Expiration::AtHeight(env.block.height + 100);
+        let msg = ExecuteMsg::BatchAllowances {
+            updates: vec![
+                AllowanceUpdate::Increase {
+                    spender: game1.clone(),
+                    amount: Uint128::new(1000),
+                    expires: Some(expires),
+                    allowed_recipients: None,
+                },
+                AllowanceUpdate::Decrease {
+                    spender: game2.clone(),
+                    amount: Uint128::new(2000),
+                    expires: None,
+                },
+                AllowanceUpdate::Set {
+                    spender: game3.clone(),
+                    amount: Uint128::new(777),
+                    expires: None,
+                },
+            ],
+        };
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        assert_eq!(res.attributes[0], attr("action", "batch_allowances"));
+        let spenders: Vec<_> = res
+            .attributes
+            .iter()
+            .filter(|a| a.key == "spender")
+            .map(|a| a.value.clone())
+            .collect();
+        assert_eq!(spenders, vec![game1.clone(), game2.clone(), game3.clone()]);
+
+        assert_eq!(
+            query_allowance(deps.as_ref(), owner.clone(), game1).unwrap(),
+            AllowanceResponse {
+                allowance: Uint128::new(1000),
+                expires,
+                allowed_recipients: None,
+            }
+        );
+        assert_eq!(
+            query_allowance(deps.as_ref(), owner.clone(), game2).unwrap().allowance,
+            Uint128::new(3000)
+        );
+        assert_eq!(
+            query_allowance(deps.as_ref(), owner.clone(), game3.clone()).unwrap().allowance,
+            Uint128::new(777)
+        );
+
+        // set to zero removes the allowance
+        let msg = ExecuteMsg::BatchAllowances {
+            updates: vec![AllowanceUpdate::Set {
+                spender: game3.clone(),
+                amount: Uint128::zero(),
+                expires: None,
+            }],
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+        assert_eq!(
+            query_allowance(deps.as_ref(), owner.clone(), game3).unwrap(),
+            AllowanceResponse::default()
+        );
+
+        // any invalid entry fails the whole batch
+        let msg = ExecuteMsg::BatchAllowances {
+            updates: vec![AllowanceUpdate::Set {
+                spender: owner.clone(),
+                amount: Uint128::new(1),
+                expires: None,
+            }],
+        };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+        assert_eq!(err, ContractError::CannotSetOwnAccount {});
+
+        let msg = ExecuteMsg::BatchAllowances { updates: vec![] };
+        let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+        assert_eq!(err, ContractError::EmptyBatch {});
+    }
 }
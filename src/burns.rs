@@ -0,0 +1,109 @@
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Uint128};
+use cw_storage_plus::Bound;
+
+use crate::contract::execute_burn;
+use crate::error::ContractError;
+use crate::msg::{BurnReceiptResponse, BurnReceiptsResponse};
+use crate::state::{burn_receipts, BurnReceipt, BURN_RECEIPT_COUNT};
+
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+// 소각 사유 / 참조 최대 길이 (bytes)
+pub const MAX_BURN_REASON_LEN: usize = 128;
+pub const MAX_BURN_REFERENCE_LEN: usize = 256;
+
+fn validate_reason(reason: &str, reference: Option<&str>) -> Result<(), ContractError> {
+    if reason.trim().is_empty() {
+        return Err(ContractError::InvalidBurnReason {
+            msg: "reason must not be empty".to_string(),
+        });
+    }
+    if reason.len() > MAX_BURN_REASON_LEN {
+        return Err(ContractError::InvalidBurnReason {
+            msg: format!("reason exceeds {} bytes", MAX_BURN_REASON_LEN),
+        });
+    }
+    if reference.is_some_and(|reference| reference.len() > MAX_BURN_REFERENCE_LEN) {
+        return Err(ContractError::InvalidBurnReason {
+            msg: format!("reference exceeds {} bytes", MAX_BURN_REFERENCE_LEN),
+        });
+    }
+    Ok(())
+}
+
+/// 일반 소각과 같은 검사를 거친 뒤 영수증 저장
+pub fn execute_burn_with_reason(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+    reason: String,
+    reference: Option<String>,
+) -> Result<Response, ContractError> {
+    validate_reason(&reason, reference.as_deref())?;
+    if amount.is_zero() {
+        return Err(ContractError::InvalidAmount {});
+    }
+
+    let response = execute_burn(deps.branch(), env.clone(), info.clone(), amount)?;
+
+    let id = BURN_RECEIPT_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    BURN_RECEIPT_COUNT.save(deps.storage, &id)?;
+    burn_receipts().save(
+        deps.storage,
+        id,
+        &BurnReceipt {
+            id,
+            burner: info.sender,
+            amount,
+            reason: reason.clone(),
+            reference: reference.clone(),
+            height: env.block.height,
+            time: env.block.time,
+        },
+    )?;
+
+    Ok(response
+        .add_attribute("receipt_id", id.to_string())
+        .add_attribute("reason", reason)
+        .add_attribute("reference", reference.unwrap_or_default()))
+}
+
+fn to_response(receipt: BurnReceipt) -> BurnReceiptResponse {
+    BurnReceiptResponse {
+        id: receipt.id,
+        burner: receipt.burner.into(),
+        amount: receipt.amount,
+        reason: receipt.reason,
+        reference: receipt.reference,
+        height: receipt.height,
+        time: receipt.time,
+    }
+}
+
+pub fn query_burn_receipt(deps: Deps, id: u64) -> StdResult<BurnReceiptResponse> {
+    Ok(to_response(burn_receipts().load(deps.storage, id)?))
+}
+
+pub fn query_burn_receipts_by_burner(
+    deps: Deps,
+    burner: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<BurnReceiptsResponse> {
+    let burner_addr = deps.api.addr_validate(&burner)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let receipts = burn_receipts()
+        .idx
+        .burner
+        .prefix(burner_addr)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, receipt)| to_response(receipt)))
+        .collect::<StdResult<_>>()?;
+    Ok(BurnReceiptsResponse { receipts })
+}
@@ -4,11 +4,11 @@ use std::str::FromStr;
 use cosmwasm_std::entry_point;
 use cosmwasm_std::Order::Ascending;
 use cosmwasm_std::{
-    to_json_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult, Uint128 
+    to_json_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, StdResult, Uint128 
 };
 use cw2::{ensure_from_older_version, set_contract_version};
 use cw20::{
-    BalanceResponse, Cw20ReceiveMsg, DownloadLogoResponse, EmbeddedLogo, 
+    BalanceResponse, Cw20Coin, Cw20ReceiveMsg, DownloadLogoResponse, EmbeddedLogo, 
     Logo, LogoInfo, MarketingInfoResponse, MinterResponse, TokenInfoResponse,
 };
 
@@ -16,22 +16,80 @@ use cw20::{
 use cosmwasm_std::Addr;
 
 use crate::allowances::{
-    execute_burn_from, execute_decrease_allowance, execute_increase_allowance, execute_send_from,
-    execute_transfer_from, query_allowance,
+    execute_approve_operator, execute_batch_allowances, execute_burn_from,
+    execute_decrease_allowance,
+    execute_increase_allowance, execute_revoke_operator, execute_send_from, execute_transfer_from,
+    query_allowance, query_is_operator,
+};
+use crate::burns::{
+    execute_burn_with_reason, query_burn_receipt, query_burn_receipts_by_burner,
+};
+use crate::emission::{
+    execute_release_emissions, execute_set_emission_schedule, query_emission_info,
+};
+use crate::enumerable::{
+    query_all_accounts, query_frozen_accounts, query_minters, query_operators, query_owner_allowances, query_spender_allowances,
 };
-use crate::enumerable::{query_all_accounts, query_owner_allowances, query_spender_allowances};
 use crate::error::ContractError;
-use crate::fee::{add_fee_attributes, calculate_fee, validate_fee_config, FeeTokenType, FeeType};
+use crate::fee::{
+    add_fee_attributes, calculate_fee, compute_fee_amount, fee_distribution_msgs,
+    validate_fee_config, BatchFeeMode, FeeCalculationResult, FeeTokenType, FeeType,
+};
 
 #[cfg(test)]
 use crate::fee::apply_fee_transfers;
 
+
+use crate::freeze::{assert_not_frozen, execute_freeze_account, execute_unfreeze_account};
+use crate::hooks::{
+    balance_change_hooks, execute_add_hook, execute_remove_hook, handle_balance_hook_reply,
+    query_hooks, BALANCE_HOOK_REPLY_ID,
+};
+use crate::htlc::{
+    execute_claim_htlc, execute_lock_htlc, execute_refund_htlc, query_htlc,
+    query_htlcs_by_recipient, query_htlcs_by_sender,
+};
+use crate::limits::{
+    assert_max_wallet, assert_transfer_limits, execute_set_transfer_cooldown,
+    execute_set_transfer_limits, query_next_transfer, query_transfer_limits,
+    record_outgoing_transfer,
+};
+use crate::memo::{
+    add_memo_attributes, assert_memo_attached, execute_set_memo_required, query_memo_required,
+    wrap_memo_msg,
+};
+use crate::minters::{
+    authorize_mint, execute_accept_minter,
+    execute_cancel_minter_proposal, execute_propose_minter, execute_remove_minter,
+    execute_set_mint_rate_limit, execute_set_minter, query_mint_capacity, query_pending_minter,
+    set_primary_minter,
+};
 use crate::msg::{
-     ConfigInfo, ExecuteMsg, FeeCollectorInput, FeeCollectorResponse, FeeConfigResponse, FeeGranterResponse, InstantiateMsg, MigrateMsg, QueryMsg, TotalSupplyResponse
+     BatchTransferItem, ConfigInfo, ExecuteMsg, FeeCollectorInput, FeeCollectorResponse, FeeConfigResponse, FeeGranterResponse, InstantiateMsg, MemoInfo, MigrateMsg, QueryMsg, TotalSupplyResponse
+};
+use crate::pause::{assert_not_paused, execute_pause, execute_unpause, query_pause_status};
+use crate::reversible::{
+    execute_accept_transfer, execute_reverse_transfer, execute_reversible_transfer,
+    execute_set_reversible_window, query_pending_transfer, query_pending_transfers,
+    settle_matured_transfers, triggers_auto_settle,
+};
+use crate::scheduled::{
+    execute_cancel_scheduled, execute_release_scheduled, execute_schedule_transfer,
+    query_scheduled_transfer,
+};
+use crate::stream::{
+    execute_cancel_stream, execute_create_stream, execute_withdraw_stream, query_stream,
+    query_streams_by_recipient, query_streams_by_sender,
+};
+use crate::supply::{
+    assert_within_max_supply, execute_lower_max_supply, max_supply, query_supply_cap,
+    query_total_supply_at_height, reconcile_max_supply, record_total_supply,
+    seed_total_supply_history, set_max_supply, validate_max_supply_change,
 };
 use crate::state::{
     ExtendedTokenInfo, FeeCollectorInfo, FeeConfig, MinterData, TokenInfo, ALLOWANCES, ALLOWANCES_SPENDER, BALANCES, CONFIG, EXTENDED_INFO, FEE_CONFIG, LOGO, MARKETING_INFO, TOKEN_INFO
 };
+use crate::vesting::{assert_unlocked, create_vesting, execute_grant_vested, query_vesting_info};
 
 // Contract name and version
 const CONTRACT_NAME: &str = "crates.io:iup-token";
@@ -91,7 +149,7 @@ fn verify_png_logo(logo: &[u8]) -> Result<(), ContractError> {
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
@@ -107,7 +165,7 @@ pub fn instantiate(
 
     let mut total_supply = Uint128::zero();
     
-    // initial_balances를 Vec<Cw20Coin>으로 처리
+    // 초기 잔액 지급 (vesting 지정 시 스케줄 등록)
     for balance in msg.initial_balances.iter() {
         #[cfg(test)]
         let address = Addr::unchecked(&balance.address);
@@ -116,6 +174,9 @@ pub fn instantiate(
         let address = deps.api.addr_validate(&balance.address)?;
         
         BALANCES.save(deps.storage, &address, &balance.amount)?;
+        if let Some(schedule) = &balance.vesting {
+            create_vesting(deps.storage, &address, balance.amount, schedule.clone())?;
+        }
 
         total_supply = total_supply
             .checked_add(balance.amount)
@@ -155,6 +216,7 @@ pub fn instantiate(
         mint,
     };
     TOKEN_INFO.save(deps.storage, &token_info)?;
+    record_total_supply(deps.storage, env.block.height, total_supply)?;
 
     // 마케팅 정보 처리
     if let Some(marketing) = msg.marketing {
@@ -214,7 +276,8 @@ pub fn instantiate(
         minter: None,
         transfer_fee: None,
         fee_collector: None,
-        max_supply: None,
+        // minter cap을 최대 발행량으로 사용 (이후 낮출 수만 있음)
+        max_supply: token_info.get_cap(),
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -239,45 +302,151 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    match msg {
+    // 정지된 작업이면 거부 (관리자 메시지는 항상 허용)
+    assert_not_paused(deps.storage, &msg)?;
+
+    // 되돌림 기간이 지난 수신 대기 전송은 recipient의 다음 전송 시 자동 확정
+    let settled = if triggers_auto_settle(&msg) {
+        settle_matured_transfers(deps.branch(), &env, &info.sender)?
+    } else {
+        Response::new()
+    };
+
+    let response = match msg {
         // CW20 기본 기능
-        ExecuteMsg::Transfer { recipient, amount } => execute_transfer(deps, env, info, recipient, amount),
+        ExecuteMsg::Transfer { recipient, amount } => {
+            execute_transfer(deps, env, info, recipient, amount, None)
+        }
+        ExecuteMsg::TransferWithMemo {
+            recipient,
+            amount,
+            memo,
+        } => execute_transfer(deps, env, info, recipient, amount, Some(memo)),
+        ExecuteMsg::BatchTransfer { transfers } => {
+            execute_batch_transfer(deps, env, info, transfers)
+        }
         ExecuteMsg::Burn { amount } => execute_burn(deps, env, info, amount),
+        ExecuteMsg::BurnWithReason {
+            amount,
+            reason,
+            reference,
+        } => execute_burn_with_reason(deps, env, info, amount, reason, reference),
         ExecuteMsg::Send {
             contract,
             amount,
             msg,
-        } => execute_send(deps, env, info, contract, amount, msg),
+        } => execute_send(deps, env, info, contract, amount, msg, None),
+        ExecuteMsg::SendWithMemo {
+            contract,
+            amount,
+            msg,
+            memo,
+        } => execute_send(deps, env, info, contract, amount, msg, Some(memo)),
         ExecuteMsg::Mint { recipient, amount } => {
             execute_mint(deps, env, info, recipient, amount)
         }
+        ExecuteMsg::BatchMint { mints } => execute_batch_mint(deps, env, info, mints),
         ExecuteMsg::IncreaseAllowance {
             spender,
             amount,
             expires,
-        } => execute_increase_allowance(deps, env, info, spender, amount, expires),
+            allowed_recipients,
+        } => execute_increase_allowance(
+            deps,
+            env,
+            info,
+            spender,
+            amount,
+            expires,
+            allowed_recipients,
+        ),
         ExecuteMsg::DecreaseAllowance {
             spender,
             amount,
             expires,
         } => execute_decrease_allowance(deps, env, info, spender, amount, expires),
+        ExecuteMsg::ScheduleTransfer {
+            recipient,
+            amount,
+            release_at,
+            cancellable_until,
+        } => execute_schedule_transfer(
+            deps,
+            env,
+            info,
+            recipient,
+            amount,
+            release_at,
+            cancellable_until,
+        ),
+        ExecuteMsg::GrantVested {
+            recipient,
+            amount,
+            schedule,
+            mint,
+        } => execute_grant_vested(deps, env, info, recipient, amount, schedule, mint),
+        ExecuteMsg::ReleaseScheduled { id } => execute_release_scheduled(deps, env, info, id),
+        ExecuteMsg::CancelScheduled { id } => execute_cancel_scheduled(deps, env, info, id),
+        ExecuteMsg::LockHtlc {
+            recipient,
+            amount,
+            hashlock,
+            timelock,
+        } => execute_lock_htlc(deps, env, info, recipient, amount, hashlock, timelock),
+        ExecuteMsg::ClaimHtlc { id, preimage } => execute_claim_htlc(deps, env, info, id, preimage),
+        ExecuteMsg::RefundHtlc { id } => execute_refund_htlc(deps, env, info, id),
+        ExecuteMsg::ReversibleTransfer { recipient, amount } => {
+            execute_reversible_transfer(deps, env, info, recipient, amount)
+        }
+        ExecuteMsg::ReverseTransfer { id } => execute_reverse_transfer(deps, env, info, id),
+        ExecuteMsg::AcceptTransfer { id } => execute_accept_transfer(deps, env, info, id),
+        ExecuteMsg::CreateStream {
+            recipient,
+            total,
+            start,
+            end,
+        } => execute_create_stream(deps, env, info, recipient, total, start, end),
+        ExecuteMsg::WithdrawStream { id } => execute_withdraw_stream(deps, env, info, id),
+        ExecuteMsg::CancelStream { id } => execute_cancel_stream(deps, env, info, id),
+        ExecuteMsg::BatchAllowances { updates } => {
+            execute_batch_allowances(deps, env, info, updates)
+        }
         ExecuteMsg::TransferFrom {
             owner,
             recipient,
             amount,
-        } => execute_transfer_from(deps, env, info, owner, recipient, amount),
+        } => execute_transfer_from(deps, env, info, owner, recipient, amount, None),
+        ExecuteMsg::TransferFromWithMemo {
+            owner,
+            recipient,
+            amount,
+            memo,
+        } => execute_transfer_from(deps, env, info, owner, recipient, amount, Some(memo)),
         ExecuteMsg::BurnFrom { owner, amount } => execute_burn_from(deps, env, info, owner, amount),
+        ExecuteMsg::ApproveOperator { operator, expires } => {
+            execute_approve_operator(deps, env, info, operator, expires)
+        }
+        ExecuteMsg::RevokeOperator { operator } => {
+            execute_revoke_operator(deps, env, info, operator)
+        }
         ExecuteMsg::SendFrom {
             owner,
             contract,
             amount,
             msg,
-        } => execute_send_from(deps, env, info, owner, contract, amount, msg),
+        } => execute_send_from(deps, env, info, owner, contract, amount, msg, None),
+        ExecuteMsg::SendFromWithMemo {
+            owner,
+            contract,
+            amount,
+            msg,
+            memo,
+        } => execute_send_from(deps, env, info, owner, contract, amount, msg, Some(memo)),
         
         // 마케팅 관련 기능
         ExecuteMsg::UpdateMarketing {
@@ -286,9 +455,68 @@ pub fn execute(
             marketing,
         } => execute_update_marketing(deps, env, info, project, description, marketing),
         ExecuteMsg::UploadLogo(logo) => execute_upload_logo(deps, env, info, logo),
-        ExecuteMsg::UpdateMinter { new_minter } => {
-            execute_update_minter(deps, env, info, new_minter)
+        ExecuteMsg::UpdateMinter { new_minter, force } => {
+            execute_update_minter(deps, env, info, new_minter, force)
+        }
+        ExecuteMsg::ProposeMinter { new_minter, expiry } => {
+            execute_propose_minter(deps, env, info, new_minter, expiry)
+        }
+        ExecuteMsg::AcceptMinter {} => execute_accept_minter(deps, env, info),
+        ExecuteMsg::CancelMinterProposal {} => execute_cancel_minter_proposal(deps, info),
+        ExecuteMsg::SetMemoRequired { address, required } => {
+            execute_set_memo_required(deps, info, address, required)
+        }
+        ExecuteMsg::FreezeAccount { address, reason } => {
+            execute_freeze_account(deps, info, address, reason)
+        }
+        ExecuteMsg::UnfreezeAccount { address } => {
+            execute_unfreeze_account(deps, info, address)
+        }
+        ExecuteMsg::SetTransferLimits {
+            max_tx_amount,
+            max_wallet_balance,
+            exempt,
+        } => execute_set_transfer_limits(deps, info, max_tx_amount, max_wallet_balance, exempt),
+        ExecuteMsg::SetTransferCooldown { cooldown } => {
+            execute_set_transfer_cooldown(deps, info, cooldown)
         }
+        ExecuteMsg::SetMinter {
+            address,
+            allowance,
+            expires,
+        } => execute_set_minter(deps, env, info, address, allowance, expires),
+        ExecuteMsg::RemoveMinter { address } => execute_remove_minter(deps, info, address),
+        ExecuteMsg::SetMintRateLimit { minter, limit } => {
+            execute_set_mint_rate_limit(deps, info, minter, limit)
+        }
+        ExecuteMsg::LowerMaxSupply { max_supply } => {
+            execute_lower_max_supply(deps, info, max_supply)
+        }
+        ExecuteMsg::SetEmissionSchedule {
+            rate_per_block,
+            start_height,
+            halving_interval,
+            recipients,
+        } => execute_set_emission_schedule(
+            deps,
+            env,
+            info,
+            rate_per_block,
+            start_height,
+            halving_interval,
+            recipients,
+        ),
+        ExecuteMsg::ReleaseEmissions {} => execute_release_emissions(deps, env),
+        ExecuteMsg::AddHook {
+            address,
+            revert_on_error,
+        } => execute_add_hook(deps, info, address, revert_on_error),
+        ExecuteMsg::RemoveHook { address } => execute_remove_hook(deps, info, address),
+        ExecuteMsg::SetReversibleWindow { window } => {
+            execute_set_reversible_window(deps, info, window)
+        }
+        ExecuteMsg::Pause { operations } => execute_pause(deps, info, operations),
+        ExecuteMsg::Unpause { operations } => execute_unpause(deps, info, operations),
         ExecuteMsg::SetFeeGranter { address } => {
             execute_set_fee_granter(deps, info, address)
         }
@@ -303,43 +531,80 @@ pub fn execute(
             token_type,
             collectors,
             is_active,
-        } => execute_set_fee_config(deps, info, fee_type, token_type, collectors, is_active),
-    }
+            batch_fee_mode,
+        } => execute_set_fee_config(
+            deps,
+            info,
+            fee_type,
+            token_type,
+            collectors,
+            is_active,
+            batch_fee_mode,
+        ),
+    }?;
+
+    Ok(response
+        .add_submessages(settled.messages)
+        .add_events(settled.events))
 }
 
 pub fn execute_transfer(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     recipient: String,
     amount: Uint128,
+    memo: Option<MemoInfo>,
 ) -> Result<Response, ContractError> {
     #[cfg(test)]
     let rcpt_addr = Addr::unchecked(&recipient);
     
     #[cfg(not(test))]
     let rcpt_addr = deps.api.addr_validate(&recipient)?;
+
+    assert_not_frozen(deps.storage, &[&info.sender, &rcpt_addr])?;
+    if let Some(memo) = &memo {
+        memo.validate()?;
+    }
+    assert_memo_attached(deps.storage, &rcpt_addr, memo.as_ref())?;
     
     // 잔액 확인
     let sender_balance = BALANCES.load(deps.storage, &info.sender)?;
     if sender_balance < amount {
         return Err(ContractError::InsufficientFunds {});
     }
+    // 베스팅으로 잠긴 물량은 사용 불가
+    assert_unlocked(deps.storage, &env.block, &info.sender, sender_balance - amount)?;
     
     // 수수료 계산
     let fee_result = calculate_fee(deps.as_ref(), amount, &info.sender)?;
+    assert_transfer_limits(
+        deps.storage,
+        &info.sender,
+        &rcpt_addr,
+        amount,
+        fee_result.transfer_amount,
+    )?;
+    record_outgoing_transfer(deps.storage, &env.block, &info.sender)?;
     
     // 발신자 잔액 감소
-    BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
+    let new_sender_balance = BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
         Ok(balance.unwrap_or_default().checked_sub(amount)?)
     })?;
     
     // 수신자 잔액 증가 (수수료 차감 후)
-    BALANCES.update(deps.storage, &rcpt_addr, |balance| -> StdResult<_> {
+    let new_rcpt_balance = BALANCES.update(deps.storage, &rcpt_addr, |balance| -> StdResult<_> {
         Ok(balance.unwrap_or_default() + fee_result.transfer_amount)
     })?;
+    let hook_msgs = balance_change_hooks(
+        deps.storage,
+        vec![
+            (info.sender.clone(), sender_balance, new_sender_balance),
+            (rcpt_addr, new_rcpt_balance - fee_result.transfer_amount, new_rcpt_balance),
+        ],
+    )?;
 
-     // 테스트 환경에서만 수수료 이체 직접 처리
+    // 테스트 환경에서만 수수료 이체 직접 처리
     #[cfg(test)]
     apply_fee_transfers(deps.storage, &fee_result)?;
     
@@ -348,7 +613,9 @@ pub fn execute_transfer(
         .add_attribute("action", "transfer")
         .add_attribute("from", info.sender.to_string())
         .add_attribute("to", recipient)
-        .add_attribute("amount", fee_result.transfer_amount);
+        .add_attribute("amount", fee_result.transfer_amount)
+        .add_submessages(hook_msgs);
+    response = add_memo_attributes(response, memo.as_ref());
     
     // 수수료 메시지가 있으면 추가 (클론을 사용하여 소유권 이동 방지)
     if !fee_result.fee_msgs.is_empty() {
@@ -362,61 +629,210 @@ pub fn execute_transfer(
     Ok(response)
 }
 
+pub fn execute_batch_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    transfers: Vec<BatchTransferItem>,
+) -> Result<Response, ContractError> {
+    if transfers.is_empty() {
+        return Err(ContractError::EmptyBatch {});
+    }
+    assert_not_frozen(deps.storage, &[&info.sender])?;
+
+    // 총액 기준 잔액 확인 (한 번만)
+    let total = transfers.iter().try_fold(Uint128::zero(), |acc, t| {
+        acc.checked_add(t.amount)
+            .map_err(|_| ContractError::InvalidAmount {})
+    })?;
+    // 모든 건이 0이면 수수료 분담 비율을 계산할 수 없음
+    if total.is_zero() {
+        return Err(ContractError::InvalidAmount {});
+    }
+    let sender_balance = BALANCES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    if sender_balance < total {
+        return Err(ContractError::InsufficientFunds {});
+    }
+    assert_unlocked(deps.storage, &env.block, &info.sender, sender_balance - total)?;
+    record_outgoing_transfer(deps.storage, &env.block, &info.sender)?;
+
+    // 수수료 설정은 한 번만 로드
+    let fee_config = FEE_CONFIG.may_load(deps.storage)?;
+    let fee_mode = fee_config
+        .as_ref()
+        .map(|c| c.batch_fee_mode.clone())
+        .unwrap_or_default();
+
+    // 각 건의 수수료 분담액 계산
+    let fees = match fee_mode {
+        BatchFeeMode::PerTransfer => transfers
+            .iter()
+            .map(|t| compute_fee_amount(fee_config.as_ref(), t.amount))
+            .collect::<Result<Vec<_>, _>>()?,
+        BatchFeeMode::Total => {
+            // 합계 수수료를 금액 비율로 나누고 나머지는 마지막 건에 부과
+            let total_fee = compute_fee_amount(fee_config.as_ref(), total)?;
+            let mut fees: Vec<Uint128> = transfers
+                .iter()
+                .map(|t| total_fee.multiply_ratio(t.amount, total))
+                .collect();
+            let assigned = fees.iter().fold(Uint128::zero(), |acc, f| acc + *f);
+            if let Some(last) = fees.last_mut() {
+                *last += total_fee - assigned;
+            }
+            fees
+        }
+    };
+
+    // 발신자 잔액 감소 (한 번만)
+    BALANCES.save(deps.storage, &info.sender, &(sender_balance - total))?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "batch_transfer")
+        .add_attribute("from", info.sender.to_string())
+        .add_attribute("count", transfers.len().to_string());
+
+    let mut changes = vec![(info.sender.clone(), sender_balance, sender_balance - total)];
+    let mut total_fee = Uint128::zero();
+    let mut total_received = Uint128::zero();
+    for (transfer, fee) in transfers.into_iter().zip(fees) {
+        #[cfg(test)]
+        let rcpt_addr = Addr::unchecked(&transfer.recipient);
+
+        #[cfg(not(test))]
+        let rcpt_addr = deps.api.addr_validate(&transfer.recipient)?;
+
+        assert_not_frozen(deps.storage, &[&rcpt_addr])?;
+        assert_memo_attached(deps.storage, &rcpt_addr, None)?;
+
+        let received = transfer
+            .amount
+            .checked_sub(fee)
+            .map_err(|_| ContractError::InvalidAmount {})?;
+        assert_transfer_limits(deps.storage, &info.sender, &rcpt_addr, transfer.amount, received)?;
+
+        // 수신자 잔액 증가 (수수료 차감 후)
+        let new_rcpt_balance = BALANCES.update(deps.storage, &rcpt_addr, |balance| -> StdResult<_> {
+            Ok(balance.unwrap_or_default() + received)
+        })?;
+        changes.push((rcpt_addr, new_rcpt_balance - received, new_rcpt_balance));
+
+        total_fee += fee;
+        total_received += received;
+        response = response
+            .add_attribute("to", transfer.recipient)
+            .add_attribute("amount", received);
+    }
+
+    response = response.add_submessages(balance_change_hooks(deps.storage, changes)?);
+
+    // 수취인별로 합산된 수수료를 한 번에 지급
+    let fee_result = FeeCalculationResult {
+        transfer_amount: total_received,
+        fee_amount: total_fee,
+        fee_msgs: fee_distribution_msgs(deps.as_ref(), fee_config.as_ref(), total_fee, &info.sender)?,
+    };
+
+    // 테스트 환경에서만 수수료 이체 직접 처리
+    #[cfg(test)]
+    apply_fee_transfers(deps.storage, &fee_result)?;
+
+    if !fee_result.fee_msgs.is_empty() {
+        response = response.add_messages(fee_result.fee_msgs.clone());
+    }
+    response = add_fee_attributes(response, &fee_result, fee_config.as_ref());
+
+    Ok(response)
+}
+
 pub fn execute_burn(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     amount: Uint128,
 ) -> Result<Response, ContractError> {
+    assert_not_frozen(deps.storage, &[&info.sender])?;
+
     // lower balance
-    BALANCES.update(
+    let new_balance = BALANCES.update(
         deps.storage,
         &info.sender,
         |balance: Option<Uint128>| -> StdResult<_> {
             Ok(balance.unwrap_or_default().checked_sub(amount)?)
         },
     )?;
+    assert_unlocked(deps.storage, &env.block, &info.sender, new_balance)?;
     // reduce total_supply
-    TOKEN_INFO.update(deps.storage, |mut info| -> StdResult<_> {
+    let token_info = TOKEN_INFO.update(deps.storage, |mut info| -> StdResult<_> {
         info.total_supply = info.total_supply.checked_sub(amount)?;
         Ok(info)
     })?;
+    record_total_supply(deps.storage, env.block.height, token_info.total_supply)?;
+
+    let hook_msgs = balance_change_hooks(
+        deps.storage,
+        vec![(info.sender.clone(), new_balance + amount, new_balance)],
+    )?;
 
     let res = Response::new()
         .add_attribute("action", "burn")
         .add_attribute("from", info.sender)
-        .add_attribute("amount", amount);
+        .add_attribute("amount", amount)
+        .add_submessages(hook_msgs);
     Ok(res)
 }
 
 pub fn execute_send(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     contract: String,
     amount: Uint128,
     msg: Binary,
+    memo: Option<MemoInfo>,
 ) -> Result<Response, ContractError> {
     let rcpt_addr = deps.api.addr_validate(&contract)?;
+    assert_not_frozen(deps.storage, &[&info.sender, &rcpt_addr])?;
+
+    // 메모가 있으면 수신 컨트랙트가 받을 메시지에 함께 담아 전달
+    let msg = wrap_memo_msg(msg, memo.as_ref())?;
+    assert_memo_attached(deps.storage, &rcpt_addr, memo.as_ref())?;
     
     // 잔액 확인
     let sender_balance = BALANCES.load(deps.storage, &info.sender)?;
     if sender_balance < amount {
         return Err(ContractError::InsufficientFunds {});
     }
+    // 베스팅으로 잠긴 물량은 사용 불가
+    assert_unlocked(deps.storage, &env.block, &info.sender, sender_balance - amount)?;
     
     // 수수료 계산 (새로운 방식 사용)
     let fee_result = calculate_fee(deps.as_ref(), amount, &info.sender)?;
+    assert_transfer_limits(
+        deps.storage,
+        &info.sender,
+        &rcpt_addr,
+        amount,
+        fee_result.transfer_amount,
+    )?;
+    record_outgoing_transfer(deps.storage, &env.block, &info.sender)?;
     
     // 발신자 잔액 감소
-    BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
+    let new_sender_balance = BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
         Ok(balance.unwrap_or_default().checked_sub(amount)?)
     })?;
     
     // 수신자 잔액 증가 (수수료 차감 후)
-    BALANCES.update(deps.storage, &rcpt_addr, |balance| -> StdResult<_> {
+    let new_rcpt_balance = BALANCES.update(deps.storage, &rcpt_addr, |balance| -> StdResult<_> {
         Ok(balance.unwrap_or_default() + fee_result.transfer_amount)
     })?;
+    let hook_msgs = balance_change_hooks(
+        deps.storage,
+        vec![
+            (info.sender.clone(), sender_balance, new_sender_balance),
+            (rcpt_addr, new_rcpt_balance - fee_result.transfer_amount, new_rcpt_balance),
+        ],
+    )?;
 
     // 테스트 환경에서만 수수료 이체 직접 처리
     #[cfg(test)]
@@ -435,7 +851,9 @@ pub fn execute_send(
                 msg,
             }
             .into_cosmos_msg(contract)?,
-        );
+        )
+        .add_submessages(hook_msgs);
+    response = add_memo_attributes(response, memo.as_ref());
     
     // 수수료 메시지가 있으면 추가 (클론을 사용하여 소유권 이동 방지)
     if !fee_result.fee_msgs.is_empty() {
@@ -451,7 +869,7 @@ pub fn execute_send(
 
 pub fn execute_mint(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     recipient: String,
     amount: Uint128,
@@ -460,47 +878,101 @@ pub fn execute_mint(
         .may_load(deps.storage)?
         .ok_or(ContractError::Unauthorized {})?;
 
-    if config
-        .mint
-        .as_ref()
-        .ok_or(ContractError::Unauthorized {})?
-        .minter
-        != info.sender
-    {
-        return Err(ContractError::Unauthorized {});
-    }
+    authorize_mint(deps.storage, &env.block, &info.sender, amount)?;
+
+    let rcpt_addr = deps.api.addr_validate(&recipient)?;
+    assert_not_frozen(deps.storage, &[&info.sender, &rcpt_addr])?;
+    assert_max_wallet(deps.storage, &rcpt_addr, amount)?;
 
     // update supply and enforce cap
-    config.total_supply += amount;
-    if let Some(limit) = config.get_cap() {
-        if config.total_supply > limit {
-            return Err(ContractError::CannotExceedCap {});
-        }
-    }
+    config.total_supply = config
+        .total_supply
+        .checked_add(amount)
+        .map_err(|_| ContractError::InvalidAmount {})?;
+    assert_within_max_supply(deps.storage, config.total_supply)?;
     TOKEN_INFO.save(deps.storage, &config)?;
+    record_total_supply(deps.storage, env.block.height, config.total_supply)?;
 
     // add amount to recipient balance
-    let rcpt_addr = deps.api.addr_validate(&recipient)?;
-    BALANCES.update(
+    let new_balance = BALANCES.update(
         deps.storage,
         &rcpt_addr,
         |balance: Option<Uint128>| -> StdResult<_> { Ok(balance.unwrap_or_default() + amount) },
     )?;
+    let hook_msgs =
+        balance_change_hooks(deps.storage, vec![(rcpt_addr, new_balance - amount, new_balance)])?;
 
     let res = Response::new()
         .add_attribute("action", "mint")
         .add_attribute("to", recipient)
-        .add_attribute("amount", amount);
+        .add_attribute("amount", amount)
+        .add_submessages(hook_msgs);
     Ok(res)
 }
 
+pub fn execute_batch_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mints: Vec<Cw20Coin>,
+) -> Result<Response, ContractError> {
+    if mints.is_empty() {
+        return Err(ContractError::EmptyBatch {});
+    }
+    let mut config = TOKEN_INFO
+        .may_load(deps.storage)?
+        .ok_or(ContractError::Unauthorized {})?;
+
+    let total = mints.iter().try_fold(Uint128::zero(), |acc, m| {
+        acc.checked_add(m.amount)
+            .map_err(|_| ContractError::InvalidAmount {})
+    })?;
+
+    // 권한, 할당량, 속도 제한은 총액 기준으로 한 번만 확인
+    authorize_mint(deps.storage, &env.block, &info.sender, total)?;
+    assert_not_frozen(deps.storage, &[&info.sender])?;
+
+    // update supply and enforce cap (한 번만)
+    config.total_supply = config
+        .total_supply
+        .checked_add(total)
+        .map_err(|_| ContractError::InvalidAmount {})?;
+    assert_within_max_supply(deps.storage, config.total_supply)?;
+    TOKEN_INFO.save(deps.storage, &config)?;
+    record_total_supply(deps.storage, env.block.height, config.total_supply)?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "batch_mint")
+        .add_attribute("minter", info.sender.to_string())
+        .add_attribute("count", mints.len().to_string())
+        .add_attribute("total", total);
+
+    let mut changes = vec![];
+    for mint in mints {
+        let rcpt_addr = deps.api.addr_validate(&mint.address)?;
+        assert_not_frozen(deps.storage, &[&rcpt_addr])?;
+        assert_max_wallet(deps.storage, &rcpt_addr, mint.amount)?;
+
+        let new_balance = BALANCES.update(deps.storage, &rcpt_addr, |balance| -> StdResult<_> {
+            Ok(balance.unwrap_or_default() + mint.amount)
+        })?;
+        changes.push((rcpt_addr, new_balance - mint.amount, new_balance));
+        response = response
+            .add_attribute("to", mint.address)
+            .add_attribute("amount", mint.amount);
+    }
+
+    Ok(response.add_submessages(balance_change_hooks(deps.storage, changes)?))
+}
+
 pub fn execute_update_minter(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
     new_minter: Option<String>,
+    force: bool,
 ) -> Result<Response, ContractError> {
-    let mut config = TOKEN_INFO
+    let config = TOKEN_INFO
         .may_load(deps.storage)?
         .ok_or(ContractError::Unauthorized {})?;
 
@@ -508,6 +980,9 @@ pub fn execute_update_minter(
     if mint.minter != info.sender {
         return Err(ContractError::Unauthorized {});
     }
+    if !force {
+        return Err(ContractError::ForceRequired {});
+    }
 
     let minter_data = new_minter
         .map(|new_minter| deps.api.addr_validate(&new_minter))
@@ -517,19 +992,16 @@ pub fn execute_update_minter(
             cap: mint.cap,
         });
 
-    config.mint = minter_data;
-
-    TOKEN_INFO.save(deps.storage, &config)?;
+    let new_minter = minter_data
+        .as_ref()
+        .map(|m| m.minter.to_string())
+        .unwrap_or_else(|| "None".to_string());
+    // 즉시 교체 시 진행 중인 제안은 무효
+    set_primary_minter(deps.storage, minter_data)?;
 
     Ok(Response::default()
         .add_attribute("action", "update_minter")
-        .add_attribute(
-            "new_minter",
-            config
-                .mint
-                .map(|m| m.minter.into_string())
-                .unwrap_or_else(|| "None".to_string()),
-        ))
+        .add_attribute("new_minter", new_minter))
 }
 
 // 새로운 marketing 관련 실행 함수 추가
@@ -656,7 +1128,7 @@ pub fn execute_update_config(
 ) -> Result<Response, ContractError> {
     let current_config = CONFIG.load(deps.storage)?;
     let extended_info = EXTENDED_INFO.load(deps.storage)?;
-    let mut token_info = TOKEN_INFO.load(deps.storage)?;
+    let token_info = TOKEN_INFO.load(deps.storage)?;
     let new_config = *new_config;
     
     // 권한 체크 - extended_info의 admin 또는 upgrade_admin만 가능
@@ -674,28 +1146,26 @@ pub fn execute_update_config(
             return Err(ContractError::ConfigUpdateNotAllowed {});
         }
 
-        // minter 설정 업데이트
-        if let Some(new_minter) = new_config.minter.as_ref() {
-            let new_minter_data = MinterData {
-                minter: deps.api.addr_validate(&new_minter.minter)?,
-                cap: new_minter.cap,
-            };
-            
-            // Only update if different
-            if token_info.mint.as_ref() != Some(&new_minter_data) {
-                token_info.mint = Some(new_minter_data);
-                TOKEN_INFO.save(deps.storage, &token_info)?;
-            }
-        } else if token_info.mint.is_some() {
-            // Remove minter if new config has None
-            token_info.mint = None;
-            TOKEN_INFO.save(deps.storage, &token_info)?;
+        // 최대 발행량은 낮출 수만 있음
+        let current_max_supply = max_supply(deps.storage)?;
+        validate_max_supply_change(
+            current_max_supply,
+            new_config.max_supply,
+            token_info.total_supply,
+        )?;
+
+        // minter 교체/제거는 UpdateMinter { force } 또는 ProposeMinter / AcceptMinter로만 가능.
+        // 여기서는 현재 minter와 같아야 하고, cap은 최대 발행량과 같아야 함 (set_max_supply에서 반영)
+        let current_minter = token_info.mint.as_ref().map(|mint| mint.minter.as_str());
+        let requested_minter = new_config.minter.as_ref().map(|mint| mint.minter.as_str());
+        if requested_minter != current_minter {
+            return Err(ContractError::ForceRequired {});
         }
-
-        // 최대 발행량 체크 (설정된 경우)
-        if let Some(max_supply) = new_config.max_supply {
-            if token_info.total_supply > max_supply {
-                return Err(ContractError::InvalidAmount {});
+        if let Some(new_minter) = new_config.minter.as_ref() {
+            if new_minter.cap.is_some() && new_minter.cap != new_config.max_supply {
+                return Err(ContractError::InvalidConfig {
+                    msg: "Minter cap must match max_supply".to_string(),
+                });
             }
         }
 
@@ -719,6 +1189,7 @@ pub fn execute_update_config(
 
         // 설정 저장
         CONFIG.save(deps.storage, new_config)?;
+        set_max_supply(deps.storage, new_config.max_supply)?;
 
         Ok(Response::new()
             .add_attribute("method", "update_config")
@@ -788,6 +1259,7 @@ pub fn execute_set_fee_config(
     token_type: FeeTokenType,
     collectors: Vec<FeeCollectorInput>,
     is_active: bool,
+    batch_fee_mode: Option<BatchFeeMode>,
 ) -> Result<Response, ContractError> {
     // 관리자 권한 확인
     let extended_info = EXTENDED_INFO.load(deps.storage)?;
@@ -843,6 +1315,7 @@ pub fn execute_set_fee_config(
         token_type,
         collectors: fee_collectors,
         is_active,
+        batch_fee_mode: batch_fee_mode.unwrap_or_default(),
     };
     
     // 수수료 설정 유효성 검사
@@ -858,11 +1331,15 @@ pub fn execute_set_fee_config(
 
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Balance { address } => to_json_binary(&query_balance(deps, address)?),
         QueryMsg::TokenInfo {} => to_json_binary(&query_token_info(deps)?),
         QueryMsg::Minter {} => to_json_binary(&query_minter(deps)?),
+        QueryMsg::PendingMinter {} => to_json_binary(&query_pending_minter(deps)?),
+        QueryMsg::Minters { start_after, limit } => {
+            to_json_binary(&query_minters(deps, start_after, limit)?)
+        }
         QueryMsg::Allowance { owner, spender } => {
             to_json_binary(&query_allowance(deps, owner, spender)?)
         }
@@ -881,14 +1358,99 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
         )?),
+        QueryMsg::Operators {
+            owner,
+            start_after,
+            limit,
+        } => to_json_binary(&query_operators(deps, env, owner, start_after, limit)?),
+        QueryMsg::IsOperator { owner, operator } => {
+            to_json_binary(&query_is_operator(deps, env, owner, operator)?)
+        }
         QueryMsg::AllAccounts { start_after, limit } => {
             to_json_binary(&query_all_accounts(deps, start_after, limit)?)
         }
         QueryMsg::MarketingInfo {} => to_json_binary(&query_marketing_info(deps)?),
         QueryMsg::DownloadLogo {} => to_json_binary(&query_download_logo(deps)?),
+        QueryMsg::MintCapacity { minter } => {
+            to_json_binary(&query_mint_capacity(deps, env, minter)?)
+        }
         QueryMsg::TotalSupply {} => to_json_binary(&query_total_supply(deps)?),
+        QueryMsg::TotalSupplyAtHeight { height } => {
+            to_json_binary(&query_total_supply_at_height(deps, height)?)
+        }
+        QueryMsg::SupplyCap {} => to_json_binary(&query_supply_cap(deps)?),
         QueryMsg::FeeGranter {} => to_json_binary(&query_fee_granter(deps)?),
         QueryMsg::FeeConfig {} => to_json_binary(&query_fee_config(deps)?),
+        QueryMsg::ScheduledTransfer { id } => {
+            to_json_binary(&query_scheduled_transfer(deps, id)?)
+        }
+        QueryMsg::Htlc { id } => to_json_binary(&query_htlc(deps, id)?),
+        QueryMsg::HtlcsBySender {
+            sender,
+            start_after,
+            limit,
+        } => to_json_binary(&query_htlcs_by_sender(deps, sender, start_after, limit)?),
+        QueryMsg::HtlcsByRecipient {
+            recipient,
+            start_after,
+            limit,
+        } => to_json_binary(&query_htlcs_by_recipient(
+            deps,
+            recipient,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::PendingTransfer { id } => to_json_binary(&query_pending_transfer(deps, id)?),
+        QueryMsg::PendingTransfers {
+            recipient,
+            start_after,
+            limit,
+        } => to_json_binary(&query_pending_transfers(deps, recipient, start_after, limit)?),
+        QueryMsg::BurnReceipt { id } => to_json_binary(&query_burn_receipt(deps, id)?),
+        QueryMsg::BurnReceiptsByBurner {
+            burner,
+            start_after,
+            limit,
+        } => to_json_binary(&query_burn_receipts_by_burner(deps, burner, start_after, limit)?),
+        QueryMsg::Stream { id } => to_json_binary(&query_stream(deps, env, id)?),
+        QueryMsg::StreamsBySender {
+            sender,
+            start_after,
+            limit,
+        } => to_json_binary(&query_streams_by_sender(
+            deps,
+            env,
+            sender,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::StreamsByRecipient {
+            recipient,
+            start_after,
+            limit,
+        } => to_json_binary(&query_streams_by_recipient(
+            deps,
+            env,
+            recipient,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::MemoRequired { address } => {
+            to_json_binary(&query_memo_required(deps, address)?)
+        }
+        QueryMsg::VestingInfo { address } => {
+            to_json_binary(&query_vesting_info(deps, env, address)?)
+        }
+        QueryMsg::TransferLimits {} => to_json_binary(&query_transfer_limits(deps)?),
+        QueryMsg::NextTransfer { address } => {
+            to_json_binary(&query_next_transfer(deps, env, address)?)
+        }
+        QueryMsg::PauseStatus {} => to_json_binary(&query_pause_status(deps)?),
+        QueryMsg::Hooks {} => to_json_binary(&query_hooks(deps)?),
+        QueryMsg::EmissionInfo {} => to_json_binary(&query_emission_info(deps, env)?),
+        QueryMsg::FrozenAccounts { start_after, limit } => {
+            to_json_binary(&query_frozen_accounts(deps, start_after, limit)?)
+        }
     }
 }
 
@@ -910,6 +1472,7 @@ pub fn query_fee_config(deps: Deps) -> StdResult<FeeConfigResponse> {
                 token_type: config.token_type,
                 collectors,
                 is_active: config.is_active,
+                batch_fee_mode: config.batch_fee_mode,
             })
         },
         None => Ok(FeeConfigResponse {
@@ -917,6 +1480,7 @@ pub fn query_fee_config(deps: Deps) -> StdResult<FeeConfigResponse> {
             token_type: FeeTokenType::Native { denom: "".to_string() },
             collectors: vec![],
             is_active: false,
+            batch_fee_mode: BatchFeeMode::default(),
         }),
     }
 }
@@ -991,7 +1555,15 @@ fn query_total_supply(deps: Deps) -> StdResult<TotalSupplyResponse> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        BALANCE_HOOK_REPLY_ID => handle_balance_hook_reply(msg),
+        id => Err(StdError::generic_err(format!("Unknown reply id: {}", id)).into()),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let original_version =
         ensure_from_older_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
@@ -1004,5 +1576,16 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
             ALLOWANCES_SPENDER.save(deps.storage, (&spender, &owner), &allowance)?;
         }
     }
-    Ok(Response::default())
+
+    // CONFIG.max_supply와 MinterData.cap을 하나의 최대 발행량으로 통일
+    let max_supply = reconcile_max_supply(deps.storage)?;
+    // 총 발행량 기록이 없으면 현재 값부터 기록 시작
+    let seeded = seed_total_supply_history(deps.storage, env.block.height)?;
+
+    Ok(Response::default()
+        .add_attribute(
+            "max_supply",
+            max_supply.map_or_else(|| "none".to_string(), |max| max.to_string()),
+        )
+        .add_attribute("total_supply_history_seeded", seeded.to_string()))
 }
@@ -0,0 +1,277 @@
+use cosmwasm_std::{
+    BlockInfo, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Uint128,
+};
+
+#[cfg(test)]
+use cosmwasm_std::Addr;
+
+use crate::error::ContractError;
+use crate::freeze::assert_not_frozen;
+use crate::hooks::balance_change_hooks;
+use crate::limits::assert_max_wallet;
+use crate::minters::{consume_mint_rate, global_mint_remaining};
+use crate::msg::{EmissionInfoResponse, EmissionRecipient, EmissionScheduleResponse};
+use crate::state::{
+    EmissionSchedule, EmissionState, BALANCES, EMISSION_SCHEDULE, EMISSION_STATE, EXTENDED_INFO,
+    TOKEN_INFO,
+};
+use crate::supply::{max_supply, record_total_supply};
+
+/// height 블록의 블록당 발행량 (반감 반영)
+pub fn rate_at(schedule: &EmissionSchedule, height: u64) -> Uint128 {
+    if height < schedule.start_height {
+        return Uint128::zero();
+    }
+    match schedule.halving_interval {
+        Some(interval) => {
+            let halvings = (height - schedule.start_height) / interval;
+            if halvings >= 128 {
+                Uint128::zero()
+            } else {
+                schedule.rate_per_block >> (halvings as u32)
+            }
+        }
+        None => schedule.rate_per_block,
+    }
+}
+
+/// from 블록부터 to 블록 직전까지 쌓인 발행량. 반감 구간마다 나눠 계산
+pub fn accrued_emissions(schedule: &EmissionSchedule, from: u64, to: u64) -> Uint128 {
+    let mut height = from.max(schedule.start_height);
+    let mut total = Uint128::zero();
+    while height < to {
+        let rate = rate_at(schedule, height);
+        if rate.is_zero() {
+            break;
+        }
+        let epoch_end = match schedule.halving_interval {
+            Some(interval) => {
+                let halvings = (height - schedule.start_height) / interval;
+                schedule.start_height + (halvings + 1) * interval
+            }
+            None => to,
+        };
+        let end = epoch_end.min(to);
+        total = total.saturating_add(rate.saturating_mul(Uint128::from(end - height)));
+        height = end;
+    }
+    total
+}
+
+/// 현재 시점에 발행 가능한 양과 전체 발행 속도 한도에 막혀 이월할 양.
+/// 최대 발행량을 넘는 부분은 발행하지 않는다
+fn releasable(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    schedule: &EmissionSchedule,
+    state: &EmissionState,
+) -> StdResult<(Uint128, Uint128)> {
+    let accrued = state
+        .carried
+        .saturating_add(accrued_emissions(schedule, state.last_release_height, block.height));
+    let total_supply = TOKEN_INFO.load(storage)?.total_supply;
+    let mintable = match max_supply(storage)? {
+        Some(cap) => accrued.min(cap.saturating_sub(total_supply)),
+        None => accrued,
+    };
+    let amount = match global_mint_remaining(storage, block)? {
+        Some(remaining) => mintable.min(remaining),
+        None => mintable,
+    };
+    Ok((amount, mintable - amount))
+}
+
+pub fn execute_set_emission_schedule(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    rate_per_block: Uint128,
+    start_height: Option<u64>,
+    halving_interval: Option<u64>,
+    recipients: Vec<EmissionRecipient>,
+) -> Result<Response, ContractError> {
+    let extended_info = EXTENDED_INFO.load(deps.storage)?;
+    if info.sender != extended_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if recipients.is_empty() {
+        return Err(ContractError::InvalidEmissionSchedule {
+            msg: "at least one recipient is required".to_string(),
+        });
+    }
+    if halving_interval == Some(0) {
+        return Err(ContractError::InvalidEmissionSchedule {
+            msg: "halving interval must be greater than zero".to_string(),
+        });
+    }
+    let mut total_share = Decimal::zero();
+    let mut shares = vec![];
+    for recipient in recipients {
+        #[cfg(test)]
+        let address = Addr::unchecked(&recipient.address);
+        #[cfg(not(test))]
+        let address = deps.api.addr_validate(&recipient.address)?;
+
+        if recipient.share.is_zero() || recipient.share > Decimal::one() {
+            return Err(ContractError::InvalidEmissionSchedule {
+                msg: "share must be between 0 and 1".to_string(),
+            });
+        }
+        total_share += recipient.share;
+        shares.push((address, recipient.share));
+    }
+    if total_share != Decimal::one() {
+        return Err(ContractError::InvalidEmissionSchedule {
+            msg: "shares must sum to 1".to_string(),
+        });
+    }
+
+    let start_height = start_height.unwrap_or(env.block.height);
+    if start_height < env.block.height {
+        return Err(ContractError::InvalidEmissionSchedule {
+            msg: "start height must not be in the past".to_string(),
+        });
+    }
+
+    // 기존 스케줄로 쌓인 미지급분은 이월
+    let mut state = EMISSION_STATE.may_load(deps.storage)?.unwrap_or_default();
+    if let Some(previous) = EMISSION_SCHEDULE.may_load(deps.storage)? {
+        let accrued = accrued_emissions(&previous, state.last_release_height, env.block.height);
+        state.carried = state.carried.saturating_add(accrued);
+    }
+    state.last_release_height = start_height;
+    EMISSION_STATE.save(deps.storage, &state)?;
+
+    EMISSION_SCHEDULE.save(
+        deps.storage,
+        &EmissionSchedule {
+            rate_per_block,
+            start_height,
+            halving_interval,
+            recipients: shares,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_emission_schedule")
+        .add_attribute("rate_per_block", rate_per_block)
+        .add_attribute("start_height", start_height.to_string()))
+}
+
+/// 누구나 호출 가능. 쌓인 발행량을 비율대로 나눠 발행하고 나머지는 마지막 수령 주소에 배정.
+/// 동결되었거나 최대 보유량을 넘는 수령 주소의 몫은 발행하지 않고 버림 (다른 수령 주소로 넘어가지 않음)
+pub fn execute_release_emissions(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let schedule = EMISSION_SCHEDULE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoEmissionSchedule {})?;
+    let mut state = EMISSION_STATE.load(deps.storage)?;
+
+    let (amount, deferred) = releasable(deps.storage, &env.block, &schedule, &state)?;
+    if amount.is_zero() {
+        if !deferred.is_zero() {
+            return Err(ContractError::MintRateExceeded {
+                remaining: Uint128::zero(),
+            });
+        }
+        return Err(ContractError::NothingToRelease {});
+    }
+    let mut response = Response::new()
+        .add_attribute("action", "release_emissions")
+        .add_attribute("height", env.block.height.to_string());
+
+    // 동결되었거나 최대 보유량을 넘는 수령 주소의 몫은 발행하지 않고 버림
+    let mut changes = vec![];
+    let mut assigned = Uint128::zero();
+    let mut blocked = Uint128::zero();
+    let last = schedule.recipients.len() - 1;
+    for (i, (address, share)) in schedule.recipients.into_iter().enumerate() {
+        let portion = if i == last {
+            amount - assigned
+        } else {
+            amount.mul_floor(share)
+        };
+        assigned += portion;
+        if portion.is_zero() {
+            continue;
+        }
+
+        let check = assert_not_frozen(deps.storage, &[&address])
+            .and_then(|_| assert_max_wallet(deps.storage, &address, portion));
+        match check {
+            Ok(()) => {}
+            Err(ContractError::AccountFrozen { .. } | ContractError::ExceedsMaxWalletBalance { .. }) => {
+                blocked += portion;
+                response = response
+                    .add_attribute("blocked", address)
+                    .add_attribute("blocked_portion", portion);
+                continue;
+            }
+            Err(err) => return Err(err),
+        }
+        let new_balance = BALANCES.update(deps.storage, &address, |balance| -> StdResult<_> {
+            Ok(balance.unwrap_or_default() + portion)
+        })?;
+        changes.push((address.clone(), new_balance - portion, new_balance));
+        response = response
+            .add_attribute("to", address)
+            .add_attribute("portion", portion);
+    }
+
+    let minted = amount - blocked;
+    if !minted.is_zero() {
+        consume_mint_rate(deps.storage, &env.block, None, minted)?;
+        let mut config = TOKEN_INFO.load(deps.storage)?;
+        config.total_supply = config
+            .total_supply
+            .checked_add(minted)
+            .map_err(|_| ContractError::InvalidAmount {})?;
+        TOKEN_INFO.save(deps.storage, &config)?;
+        record_total_supply(deps.storage, env.block.height, config.total_supply)?;
+    }
+
+    // cap이나 수령 주소 제한에 막혀 발행하지 못한 양은 소멸, 속도 한도에 막힌 양만 다음 배출로 이월.
+    // 막힌 몫을 공용 이월분에 넣으면 다음 배출 때 다른 수령 주소에게 비율대로 나눠지므로 이월하지 않음
+    state.last_release_height = state.last_release_height.max(env.block.height);
+    state.carried = deferred;
+    state.total_released += minted;
+    EMISSION_STATE.save(deps.storage, &state)?;
+
+    Ok(response
+        .add_attribute("amount", minted)
+        .add_submessages(balance_change_hooks(deps.storage, changes)?))
+}
+
+pub fn query_emission_info(deps: Deps, env: Env) -> StdResult<EmissionInfoResponse> {
+    let state = EMISSION_STATE.may_load(deps.storage)?.unwrap_or_default();
+    let Some(schedule) = EMISSION_SCHEDULE.may_load(deps.storage)? else {
+        return Ok(EmissionInfoResponse {
+            schedule: None,
+            current_rate: Uint128::zero(),
+            last_release_height: state.last_release_height,
+            next_amount: Uint128::zero(),
+            total_released: state.total_released,
+        });
+    };
+
+    let (next_amount, _) = releasable(deps.storage, &env.block, &schedule, &state)?;
+    Ok(EmissionInfoResponse {
+        current_rate: rate_at(&schedule, env.block.height),
+        last_release_height: state.last_release_height,
+        next_amount,
+        total_released: state.total_released,
+        schedule: Some(EmissionScheduleResponse {
+            rate_per_block: schedule.rate_per_block,
+            start_height: schedule.start_height,
+            halving_interval: schedule.halving_interval,
+            recipients: schedule
+                .recipients
+                .into_iter()
+                .map(|(address, share)| EmissionRecipient {
+                    address: address.into(),
+                    share,
+                })
+                .collect(),
+        }),
+    })
+}
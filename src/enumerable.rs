@@ -1,10 +1,15 @@
-use cosmwasm_std::{Deps, Order, StdResult};
-use cw20::{
-    AllAccountsResponse, AllAllowancesResponse, AllSpenderAllowancesResponse, AllowanceInfo,
+use cosmwasm_std::{Deps, Env, Order, StdResult};
+use cw20::AllAccountsResponse;
+
+use crate::allowances::query_allowed_recipients;
+use crate::msg::{
+    AllAllowancesResponse, AllSpenderAllowancesResponse, AllowanceInfo, FrozenAccountInfo,
+    FrozenAccountsResponse, MinterInfo, MintersResponse, OperatorInfo, OperatorsResponse,
     SpenderAllowanceInfo,
 };
-
-use crate::state::{ALLOWANCES, ALLOWANCES_SPENDER, BALANCES};
+use crate::state::{
+    ALLOWANCES, ALLOWANCES_SPENDER, BALANCES, FROZEN_ACCOUNTS, MINTERS, OPERATORS,
+};
 use cw_storage_plus::Bound;
 
 // settings for pagination
@@ -26,7 +31,9 @@ pub fn query_owner_allowances(
         .range(deps.storage, start, None, Order::Ascending)
         .take(limit)
         .map(|item| {
-            item.map(|(addr, allow)| AllowanceInfo {
+            let (addr, allow) = item?;
+            Ok(AllowanceInfo {
+                allowed_recipients: query_allowed_recipients(deps.storage, &owner_addr, &addr)?,
                 spender: addr.into(),
                 allowance: allow.allowance,
                 expires: allow.expires,
@@ -51,7 +58,9 @@ pub fn query_spender_allowances(
         .range(deps.storage, start, None, Order::Ascending)
         .take(limit)
         .map(|item| {
-            item.map(|(addr, allow)| SpenderAllowanceInfo {
+            let (addr, allow) = item?;
+            Ok(SpenderAllowanceInfo {
+                allowed_recipients: query_allowed_recipients(deps.storage, &addr, &spender_addr)?,
                 owner: addr.into(),
                 allowance: allow.allowance,
                 expires: allow.expires,
@@ -61,6 +70,36 @@ pub fn query_spender_allowances(
     Ok(AllSpenderAllowancesResponse { allowances })
 }
 
+// 만료된 운영자 승인은 제외하고 반환
+pub fn query_operators(
+    deps: Deps,
+    env: Env,
+    owner: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<OperatorsResponse> {
+    let owner_addr = deps.api.addr_validate(&owner)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into_bytes()));
+
+    let operators = OPERATORS
+        .prefix(&owner_addr)
+        .range(deps.storage, start, None, Order::Ascending)
+        .filter(|item| {
+            item.as_ref()
+                .map_or(true, |(_, expires)| !expires.is_expired(&env.block))
+        })
+        .take(limit)
+        .map(|item| {
+            item.map(|(addr, expires)| OperatorInfo {
+                operator: addr.into(),
+                expires,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(OperatorsResponse { operators })
+}
+
 pub fn query_all_accounts(
     deps: Deps,
     start_after: Option<String>,
@@ -78,15 +117,62 @@ pub fn query_all_accounts(
     Ok(AllAccountsResponse { accounts })
 }
 
+pub fn query_frozen_accounts(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<FrozenAccountsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+    let accounts = FROZEN_ACCOUNTS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(addr, reason)| FrozenAccountInfo {
+                address: addr.into(),
+                reason,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    Ok(FrozenAccountsResponse { accounts })
+}
+
+pub fn query_minters(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<MintersResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+    let minters = MINTERS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(addr, quota)| MinterInfo {
+                address: addr.into(),
+                remaining: quota.remaining(),
+                allowance: quota.allowance,
+                minted: quota.minted,
+                expires: quota.expires,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    Ok(MintersResponse { minters })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use cosmwasm_std::testing::{mock_dependencies, mock_env};
     use cosmwasm_std::{from_json, Addr, Coin, DepsMut, MessageInfo, Uint128};
-    use cw20::{Cw20Coin, Expiration, TokenInfoResponse};
+    use cw20::{Expiration, TokenInfoResponse};
 
     use crate::contract::{execute, instantiate, query, query_token_info};
-    use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+    use crate::msg::{ExecuteMsg, InitialBalance, InstantiateMsg, QueryMsg};
 
     // this will set up the instantiation for other tests
     fn do_instantiate(mut deps: DepsMut, addr: &str, amount: Uint128) -> TokenInfoResponse {
@@ -94,9 +180,10 @@ mod tests {
             name: "Auto Gen".to_string(),
             symbol: "AUTO".to_string(),
             decimals: 3,
-            initial_balances: vec![Cw20Coin {
+            initial_balances: vec![InitialBalance {
                 address: addr.into(),
                 amount,
+                vesting: None,
             }],
             mint: None,
             marketing: None,
@@ -139,6 +226,7 @@ mod tests {
             spender: spender1.clone(),
             amount: allow1,
             expires: Some(expires),
+            allowed_recipients: None,
         };
         execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
 
@@ -148,6 +236,7 @@ mod tests {
             spender: spender2.clone(),
             amount: allow2,
             expires: None,
+            allowed_recipients: None,
         };
         execute(deps.as_mut(), env, info, msg).unwrap();
 
@@ -208,6 +297,7 @@ mod tests {
             spender: spender.clone(),
             amount: allow1,
             expires: Some(expires),
+            allowed_recipients: None,
         };
         execute(deps.as_mut(), env, info, msg).unwrap();
 
@@ -221,6 +311,7 @@ mod tests {
             spender: spender.clone(),
             amount: allow2,
             expires: None,
+            allowed_recipients: None,
         };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
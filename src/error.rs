@@ -1,4 +1,6 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
+use cw20::Expiration;
+use cw_controllers::HookError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -6,6 +8,9 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Hook(#[from] HookError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -25,9 +30,48 @@ pub enum ContractError {
     #[error("No allowance for this account")]
     NoAllowance {},
 
+    #[error("Invalid burn reason: {msg}")]
+    InvalidBurnReason { msg: String },
+
     #[error("Minting cannot exceed the cap")]
     CannotExceedCap {},
 
+    #[error("Max supply can only be lowered")]
+    CapCannotBeRaised {},
+
+    #[error("Invalid emission schedule: {msg}")]
+    InvalidEmissionSchedule { msg: String },
+
+    #[error("No emission schedule is configured")]
+    NoEmissionSchedule {},
+
+    #[error("No emissions to release")]
+    NothingToRelease {},
+
+    #[error("Replacing the minter directly requires force; use ProposeMinter instead")]
+    ForceRequired {},
+
+    #[error("No pending minter proposal")]
+    NoPendingMinter {},
+
+    #[error("Minter has been removed")]
+    NoMinter {},
+
+    #[error("Minter proposal has expired")]
+    MinterProposalExpired {},
+
+    #[error("Minter allowance has expired")]
+    MinterExpired {},
+
+    #[error("Minting exceeds the minter allowance: {remaining} remaining")]
+    MinterQuotaExceeded { remaining: Uint128 },
+
+    #[error("Minting exceeds the rate limit: {remaining} remaining in this epoch")]
+    MintRateExceeded { remaining: Uint128 },
+
+    #[error("Invalid mint rate limit: {msg}")]
+    InvalidMintRateLimit { msg: String },
+
     #[error("Logo binary data exceeds 5KB limit")]
     LogoTooBig {},
 
@@ -64,4 +108,76 @@ pub enum ContractError {
     
     #[error("Fee collectors percentages must sum to 100")]
     InvalidFeeDistribution {},
-}
\ No newline at end of file
+
+    #[error("Invalid memo: {msg}")]
+    InvalidMemo { msg: String },
+
+    #[error("Recipient {recipient} requires a memo")]
+    MemoRequired { recipient: String },
+
+    #[error("Scheduled transfer cannot be released yet")]
+    NotYetReleasable {},
+
+    #[error("Scheduled transfer can no longer be cancelled")]
+    NotCancellable {},
+
+    #[error("Hashlock must be a 32 byte sha256 hash")]
+    InvalidHashlock {},
+
+    #[error("Preimage does not match hashlock")]
+    InvalidPreimage {},
+
+    #[error("HTLC timelock has expired")]
+    HtlcExpired {},
+
+    #[error("HTLC cannot be refunded before its timelock")]
+    HtlcNotExpired {},
+
+    #[error("Reversal window has closed")]
+    ReversalWindowClosed {},
+
+    #[error("Transfer is still reversible")]
+    ReversalWindowOpen {},
+
+    #[error("Invalid stream: {msg}")]
+    InvalidStream { msg: String },
+
+    #[error("Nothing to withdraw from stream")]
+    NothingToWithdraw {},
+
+    #[error("Batch cannot be empty")]
+    EmptyBatch {},
+
+    #[error("Recipient {recipient} is not allowed for this allowance")]
+    RecipientNotAllowed { recipient: String },
+
+    #[error("Allowance increase overflows; request Uint128::MAX for an unlimited allowance")]
+    AllowanceOverflow {},
+
+    #[error("Allowance restricted to recipients cannot be used to burn")]
+    BurnNotAllowed {},
+
+    #[error("Transfer amount exceeds the {max} per-transaction limit")]
+    ExceedsMaxTransaction { max: Uint128 },
+
+    #[error("Balance of {address} would exceed the {max} wallet limit")]
+    ExceedsMaxWalletBalance { address: String, max: Uint128 },
+
+    #[error("Transfer cooldown active until {next}")]
+    CooldownActive { next: Expiration },
+
+    #[error("Operation {operation} is paused")]
+    Paused { operation: String },
+
+    #[error("Account {address} is frozen")]
+    AccountFrozen { address: String },
+
+    #[error("Invalid vesting schedule: {msg}")]
+    InvalidVestingSchedule { msg: String },
+
+    #[error("Address {address} already has the maximum of {max} vesting grants")]
+    TooManyVestingGrants { address: String, max: u32 },
+
+    #[error("Insufficient unlocked balance: {locked} still vesting")]
+    TokensLocked { locked: Uint128 },
+}
@@ -0,0 +1,66 @@
+use cosmwasm_std::{Addr, BlockInfo, DepsMut, StdResult, Storage, SubMsg, Uint128};
+
+use crate::error::ContractError;
+use crate::fee::{calculate_fee_with_config, FeeCalculationResult, FeeType};
+#[cfg(test)]
+use crate::fee::apply_fee_transfers;
+use crate::hooks::balance_change_hooks;
+use crate::limits::{assert_max_wallet, record_outgoing_transfer};
+use crate::state::{BALANCES, FEE_CONFIG};
+use crate::vesting::assert_unlocked;
+
+/// 발신자 잔액에서 에스크로로 이동. 잠긴 물량과 출금 쿨다운을 확인하고
+/// 잔액 변경 훅 메시지를 반환
+pub fn debit_escrow(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    sender: &Addr,
+    amount: Uint128,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let sender_balance = BALANCES.may_load(storage, sender)?.unwrap_or_default();
+    if sender_balance < amount {
+        return Err(ContractError::InsufficientFunds {});
+    }
+    assert_unlocked(storage, block, sender, sender_balance - amount)?;
+    record_outgoing_transfer(storage, block, sender)?;
+    BALANCES.save(storage, sender, &(sender_balance - amount))?;
+    Ok(balance_change_hooks(
+        storage,
+        vec![(sender.clone(), sender_balance, sender_balance - amount)],
+    )?)
+}
+
+/// 에스크로에서 recipient에게 지급. 수수료는 sender 기준으로 지급 시점에 적용하되
+/// 예치 후 설정된 고정 수수료가 지급액보다 크면 지급액까지만 부과 (지급이 막혀 에스크로에 묶이지 않도록).
+/// 확인은 모두 쓰기 전에 끝나므로 실패하면 상태가 바뀌지 않는다.
+/// 수수료 계산 결과와 잔액 변경 훅 메시지를 반환
+pub fn pay_from_escrow(
+    deps: DepsMut,
+    sender: &Addr,
+    recipient: &Addr,
+    amount: Uint128,
+) -> Result<(FeeCalculationResult, Vec<SubMsg>), ContractError> {
+    let fee_config = FEE_CONFIG.may_load(deps.storage)?.map(|mut config| {
+        if let FeeType::Fixed(fixed) = &mut config.fee_type {
+            *fixed = (*fixed).min(amount);
+        }
+        config
+    });
+    let fee_result = calculate_fee_with_config(deps.as_ref(), fee_config.as_ref(), amount, sender)?;
+    assert_max_wallet(deps.storage, recipient, fee_result.transfer_amount)?;
+
+    // 수신자 잔액 증가 (수수료 차감 후)
+    let new_balance = BALANCES.update(deps.storage, recipient, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default() + fee_result.transfer_amount)
+    })?;
+    let hook_msgs = balance_change_hooks(
+        deps.storage,
+        vec![(recipient.clone(), new_balance - fee_result.transfer_amount, new_balance)],
+    )?;
+
+    // 테스트 환경에서만 수수료 이체 직접 처리
+    #[cfg(test)]
+    apply_fee_transfers(deps.storage, &fee_result)?;
+
+    Ok((fee_result, hook_msgs))
+}
@@ -25,6 +25,17 @@ pub enum FeeTokenType {
     Cw20 { contract_addr: String },
 }
 
+/// 일괄 전송(BatchTransfer) 수수료 적용 방식
+#[cw_serde]
+#[derive(Default)]
+pub enum BatchFeeMode {
+    /// 각 전송 건마다 수수료 정책 적용
+    #[default]
+    PerTransfer,
+    /// 전체 합계에 한 번만 수수료 정책 적용 후 각 건에 비율대로 분담
+    Total,
+}
+
 /// 수수료 계산 결과 구조체
 pub struct FeeCalculationResult {
     pub transfer_amount: Uint128,       // 수수료 차감 후 전송될 금액
@@ -39,9 +50,20 @@ pub fn calculate_fee(
     sender: &Addr,
 ) -> Result<FeeCalculationResult, ContractError> {
     let fee_config = FEE_CONFIG.may_load(deps.storage)?;
-    
-    // 수수료 설정이 없으면 수수료 없이 전액 전송
-    let Some(fee_config) = fee_config else {
+    calculate_fee_with_config(deps, fee_config.as_ref(), amount, sender)
+}
+
+/// 이미 로드한 수수료 설정으로 수수료 계산
+pub fn calculate_fee_with_config(
+    deps: Deps,
+    fee_config: Option<&FeeConfig>,
+    amount: Uint128,
+    sender: &Addr,
+) -> Result<FeeCalculationResult, ContractError> {
+    let fee_amount = compute_fee_amount(fee_config, amount)?;
+
+    // 수수료가 0이면 수수료 없이 전액 전송
+    let Some(fee_config) = fee_config.filter(|_| !fee_amount.is_zero()) else {
         return Ok(FeeCalculationResult {
             transfer_amount: amount,
             fee_amount: Uint128::zero(),
@@ -49,16 +71,30 @@ pub fn calculate_fee(
         });
     };
 
-    // 수수료가 비활성화된 경우
-    if !fee_config.is_active {
-        return Ok(FeeCalculationResult {
-            transfer_amount: amount,
-            fee_amount: Uint128::zero(),
-            fee_msgs: vec![],
-        });
-    }
+    // 전송 금액 계산 (전체 금액 - 수수료)
+    let transfer_amount = amount.checked_sub(fee_amount)
+        .map_err(|_| ContractError::InvalidAmount {})?;
+
+    // 수수료 분배 메시지 생성
+    let fee_msgs = create_fee_distribution_msgs(deps, fee_config, fee_amount, sender)?;
+
+    Ok(FeeCalculationResult {
+        transfer_amount,
+        fee_amount,
+        fee_msgs,
+    })
+}
+
+/// 수수료 금액만 계산 (분배 메시지는 만들지 않음)
+pub fn compute_fee_amount(
+    fee_config: Option<&FeeConfig>,
+    amount: Uint128,
+) -> Result<Uint128, ContractError> {
+    // 수수료 설정이 없거나 비활성화된 경우
+    let Some(fee_config) = fee_config.filter(|c| c.is_active) else {
+        return Ok(Uint128::zero());
+    };
 
-    // 수수료 계산
     let fee_amount = match fee_config.fee_type {
         FeeType::Percentage(percentage) => {
             // 안전한 수치 계산 사용: amount * percentage
@@ -72,28 +108,22 @@ pub fn calculate_fee(
             fixed_amount
         }
     };
+    Ok(fee_amount)
+}
 
-    // 수수료가 0이면 수수료 없이 전액 전송
-    if fee_amount.is_zero() {
-        return Ok(FeeCalculationResult {
-            transfer_amount: amount,
-            fee_amount: Uint128::zero(),
-            fee_msgs: vec![],
-        });
+/// 합산된 수수료를 수취인별로 한 번에 분배하는 메시지 생성
+pub fn fee_distribution_msgs(
+    deps: Deps,
+    fee_config: Option<&FeeConfig>,
+    total_fee: Uint128,
+    sender: &Addr,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    match fee_config {
+        Some(config) if !total_fee.is_zero() => {
+            create_fee_distribution_msgs(deps, config, total_fee, sender)
+        }
+        _ => Ok(vec![]),
     }
-
-    // 전송 금액 계산 (전체 금액 - 수수료)
-    let transfer_amount = amount.checked_sub(fee_amount)
-        .map_err(|_| ContractError::InvalidAmount {})?;
-
-    // 수수료 분배 메시지 생성
-    let fee_msgs = create_fee_distribution_msgs(deps, &fee_config, fee_amount, sender)?;
-
-    Ok(FeeCalculationResult {
-        transfer_amount,
-        fee_amount,
-        fee_msgs,
-    })
 }
 
 /// 수수료 분배 메시지 생성 함수
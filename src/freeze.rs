@@ -0,0 +1,62 @@
+use cosmwasm_std::{Addr, DepsMut, MessageInfo, Response, Storage};
+
+use crate::error::ContractError;
+use crate::state::{EXTENDED_INFO, FROZEN_ACCOUNTS};
+
+/// 동결된 주소가 하나라도 있으면 거부 (송신자, 수신자, spender 모두 해당)
+pub fn assert_not_frozen(storage: &dyn Storage, addresses: &[&Addr]) -> Result<(), ContractError> {
+    for address in addresses {
+        if FROZEN_ACCOUNTS.has(storage, address) {
+            return Err(ContractError::AccountFrozen {
+                address: address.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn execute_freeze_account(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    reason: String,
+) -> Result<Response, ContractError> {
+    let extended_info = EXTENDED_INFO.load(deps.storage)?;
+    if info.sender != extended_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    #[cfg(test)]
+    let addr = Addr::unchecked(&address);
+    #[cfg(not(test))]
+    let addr = deps.api.addr_validate(&address)?;
+
+    FROZEN_ACCOUNTS.save(deps.storage, &addr, &reason)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "freeze_account")
+        .add_attribute("address", addr)
+        .add_attribute("reason", reason))
+}
+
+pub fn execute_unfreeze_account(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let extended_info = EXTENDED_INFO.load(deps.storage)?;
+    if info.sender != extended_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    #[cfg(test)]
+    let addr = Addr::unchecked(&address);
+    #[cfg(not(test))]
+    let addr = deps.api.addr_validate(&address)?;
+
+    FROZEN_ACCOUNTS.remove(deps.storage, &addr);
+
+    Ok(Response::new()
+        .add_attribute("action", "unfreeze_account")
+        .add_attribute("address", addr))
+}
@@ -0,0 +1,109 @@
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Deps, DepsMut, MessageInfo, Reply, Response, StdResult,
+    Storage, SubMsg, SubMsgResult, Uint128,
+};
+
+use crate::error::ContractError;
+use crate::msg::{BalanceChangeHookMsg, HookInfo, HooksResponse};
+use crate::state::{BALANCE_HOOKS, EXTENDED_INFO, HOOK_REVERT_ON_ERROR};
+
+/// revert_on_error가 false인 훅의 실패를 받아 무시하기 위한 reply id
+pub const BALANCE_HOOK_REPLY_ID: u64 = 1;
+
+/// 잔액 변경 (주소, 이전 잔액, 새 잔액)마다 등록된 훅 컨트랙트에 보낼 서브메시지 생성.
+/// 되돌림 설정이 없는 훅은 실패해도 reply에서 무시된다
+pub fn balance_change_hooks(
+    storage: &dyn Storage,
+    changes: Vec<(Addr, Uint128, Uint128)>,
+) -> StdResult<Vec<SubMsg>> {
+    let changes: Vec<_> = changes
+        .into_iter()
+        .filter(|(_, old, new)| old != new)
+        .map(|(address, old, new)| (address.into_string(), old, new))
+        .collect();
+    if changes.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let msg = BalanceChangeHookMsg { changes };
+    BALANCE_HOOKS.prepare_hooks(storage, |hook| {
+        let cosmos_msg = msg.clone().into_cosmos_msg(&hook)?;
+        let revert_on_error = HOOK_REVERT_ON_ERROR.may_load(storage, &hook)?.unwrap_or(true);
+        if revert_on_error {
+            Ok(SubMsg::new(cosmos_msg))
+        } else {
+            Ok(SubMsg::reply_on_error(cosmos_msg, BALANCE_HOOK_REPLY_ID)
+                .with_payload(to_json_binary(&hook)?))
+        }
+    })
+}
+
+/// 되돌림 설정이 없는 훅의 실패 기록. 원래 실행은 그대로 유지된다
+pub fn handle_balance_hook_reply(msg: Reply) -> Result<Response, ContractError> {
+    let SubMsgResult::Err(error) = msg.result else {
+        return Ok(Response::new());
+    };
+    let hook: Addr = from_json(&msg.payload)?;
+    Ok(Response::new()
+        .add_attribute("action", "balance_hook_failed")
+        .add_attribute("hook", hook)
+        .add_attribute("error", error))
+}
+
+pub fn execute_add_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    revert_on_error: bool,
+) -> Result<Response, ContractError> {
+    let extended_info = EXTENDED_INFO.load(deps.storage)?;
+    if info.sender != extended_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let hook = deps.api.addr_validate(&address)?;
+    BALANCE_HOOKS.add_hook(deps.storage, hook.clone())?;
+    HOOK_REVERT_ON_ERROR.save(deps.storage, &hook, &revert_on_error)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_hook")
+        .add_attribute("hook", hook)
+        .add_attribute("revert_on_error", revert_on_error.to_string()))
+}
+
+pub fn execute_remove_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let extended_info = EXTENDED_INFO.load(deps.storage)?;
+    if info.sender != extended_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let hook = deps.api.addr_validate(&address)?;
+    BALANCE_HOOKS.remove_hook(deps.storage, hook.clone())?;
+    HOOK_REVERT_ON_ERROR.remove(deps.storage, &hook);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_hook")
+        .add_attribute("hook", hook))
+}
+
+pub fn query_hooks(deps: Deps) -> StdResult<HooksResponse> {
+    let hooks = BALANCE_HOOKS
+        .query_hooks(deps)?
+        .hooks
+        .into_iter()
+        .map(|address| {
+            let revert_on_error = HOOK_REVERT_ON_ERROR
+                .may_load(deps.storage, &Addr::unchecked(&address))?
+                .unwrap_or(true);
+            Ok(HookInfo {
+                address,
+                revert_on_error,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(HooksResponse { hooks })
+}
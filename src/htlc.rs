@@ -0,0 +1,210 @@
+use cosmwasm_std::{Deps, DepsMut, Env, HexBinary, MessageInfo, Order, Response, StdResult, Uint128};
+use cw20::Expiration;
+use cw_storage_plus::Bound;
+use sha2::{Digest, Sha256};
+
+#[cfg(test)]
+use cosmwasm_std::Addr;
+
+use crate::error::ContractError;
+use crate::escrow::{debit_escrow, pay_from_escrow};
+use crate::fee::add_fee_attributes;
+use crate::freeze::assert_not_frozen;
+use crate::hooks::balance_change_hooks;
+use crate::limits::assert_max_tx;
+use crate::memo::assert_memo_attached;
+use crate::msg::{HtlcResponse, HtlcsResponse};
+use crate::state::{htlcs, Htlc, BALANCES, FEE_CONFIG, HTLC_COUNT};
+
+
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+pub fn execute_lock_htlc(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+    hashlock: HexBinary,
+    timelock: Expiration,
+) -> Result<Response, ContractError> {
+    #[cfg(test)]
+    let rcpt_addr = Addr::unchecked(&recipient);
+
+    #[cfg(not(test))]
+    let rcpt_addr = deps.api.addr_validate(&recipient)?;
+
+    assert_not_frozen(deps.storage, &[&info.sender, &rcpt_addr])?;
+    if amount.is_zero() {
+        return Err(ContractError::InvalidAmount {});
+    }
+    if hashlock.len() != 32 {
+        return Err(ContractError::InvalidHashlock {});
+    }
+    if timelock.is_expired(&env.block) || matches!(timelock, Expiration::Never {}) {
+        return Err(ContractError::InvalidExpiration {});
+    }
+    assert_max_tx(deps.storage, &info.sender, amount)?;
+    // 청구 시에는 메모를 붙일 수 없으므로 메모 필수 주소는 미리 거부
+    assert_memo_attached(deps.storage, &rcpt_addr, None)?;
+
+    let hook_msgs = debit_escrow(deps.storage, &env.block, &info.sender, amount)?;
+
+    let id = HTLC_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    HTLC_COUNT.save(deps.storage, &id)?;
+    htlcs().save(
+        deps.storage,
+        id,
+        &Htlc {
+            id,
+            sender: info.sender.clone(),
+            recipient: rcpt_addr,
+            amount,
+            hashlock: hashlock.clone(),
+            timelock,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "lock_htlc")
+        .add_attribute("id", id.to_string())
+        .add_attribute("from", info.sender)
+        .add_attribute("to", recipient)
+        .add_attribute("amount", amount)
+        .add_attribute("hashlock", hashlock.to_hex())
+        .add_attribute("timelock", timelock.to_string())
+        .add_submessages(hook_msgs))
+}
+
+// timelock 전까지 preimage를 아는 누구나 청구 가능. 지급은 항상 recipient에게, 수수료는 지급 시점에 적용
+pub fn execute_claim_htlc(
+    mut deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    id: u64,
+    preimage: HexBinary,
+) -> Result<Response, ContractError> {
+    let htlc = htlcs().load(deps.storage, id)?;
+    if htlc.timelock.is_expired(&env.block) {
+        return Err(ContractError::HtlcExpired {});
+    }
+    if Sha256::digest(preimage.as_slice()).as_slice() != htlc.hashlock.as_slice() {
+        return Err(ContractError::InvalidPreimage {});
+    }
+    assert_not_frozen(deps.storage, &[&htlc.sender, &htlc.recipient])?;
+    htlcs().remove(deps.storage, id)?;
+
+    let (fee_result, hook_msgs) = pay_from_escrow(
+        deps.branch(),
+        &htlc.sender,
+        &htlc.recipient,
+        htlc.amount,
+    )?;
+
+    // 상대 체인에서 사용할 수 있도록 preimage 공개
+    let mut response = Response::new()
+        .add_attribute("action", "claim_htlc")
+        .add_attribute("id", id.to_string())
+        .add_attribute("from", htlc.sender)
+        .add_attribute("to", htlc.recipient)
+        .add_attribute("amount", fee_result.transfer_amount)
+        .add_attribute("preimage", preimage.to_hex())
+        .add_submessages(hook_msgs);
+
+    if !fee_result.fee_msgs.is_empty() {
+        response = response.add_messages(fee_result.fee_msgs.clone());
+    }
+    let fee_config = FEE_CONFIG.may_load(deps.storage)?;
+    response = add_fee_attributes(response, &fee_result, fee_config.as_ref());
+
+    Ok(response)
+}
+
+// timelock이 지나면 누구나 환불 실행 가능. 전액 sender에게 반환
+pub fn execute_refund_htlc(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let htlc = htlcs().load(deps.storage, id)?;
+    if !htlc.timelock.is_expired(&env.block) {
+        return Err(ContractError::HtlcNotExpired {});
+    }
+    assert_not_frozen(deps.storage, &[&htlc.sender])?;
+    htlcs().remove(deps.storage, id)?;
+
+    let new_balance = BALANCES.update(deps.storage, &htlc.sender, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default() + htlc.amount)
+    })?;
+    let hook_msgs = balance_change_hooks(
+        deps.storage,
+        vec![(htlc.sender.clone(), new_balance - htlc.amount, new_balance)],
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "refund_htlc")
+        .add_attribute("id", id.to_string())
+        .add_attribute("from", htlc.sender)
+        .add_attribute("amount", htlc.amount)
+        .add_submessages(hook_msgs))
+}
+
+fn to_response(htlc: Htlc) -> HtlcResponse {
+    HtlcResponse {
+        id: htlc.id,
+        sender: htlc.sender.into(),
+        recipient: htlc.recipient.into(),
+        amount: htlc.amount,
+        hashlock: htlc.hashlock,
+        timelock: htlc.timelock,
+    }
+}
+
+pub fn query_htlc(deps: Deps, id: u64) -> StdResult<HtlcResponse> {
+    Ok(to_response(htlcs().load(deps.storage, id)?))
+}
+
+pub fn query_htlcs_by_sender(
+    deps: Deps,
+    sender: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<HtlcsResponse> {
+    let sender_addr = deps.api.addr_validate(&sender)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let htlcs = htlcs()
+        .idx
+        .sender
+        .prefix(sender_addr)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, htlc)| to_response(htlc)))
+        .collect::<StdResult<_>>()?;
+    Ok(HtlcsResponse { htlcs })
+}
+
+pub fn query_htlcs_by_recipient(
+    deps: Deps,
+    recipient: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<HtlcsResponse> {
+    let recipient_addr = deps.api.addr_validate(&recipient)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let htlcs = htlcs()
+        .idx
+        .recipient
+        .prefix(recipient_addr)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, htlc)| to_response(htlc)))
+        .collect::<StdResult<_>>()?;
+    Ok(HtlcsResponse { htlcs })
+}
@@ -1,10 +1,25 @@
 pub mod allowances;
+pub mod burns;
 pub mod contract;
+pub mod emission;
 pub mod enumerable;
 pub mod error;
+pub mod escrow;
 pub mod msg;
+pub mod pause;
+pub mod reversible;
+pub mod scheduled;
 pub mod state;
+pub mod stream;
+pub mod supply;
 pub mod fee;
+pub mod freeze;
+pub mod hooks;
+pub mod htlc;
+pub mod limits;
+pub mod memo;
+pub mod minters;
+pub mod vesting;
 
 pub use crate::error::ContractError;
 
@@ -12,5 +27,13 @@ pub use crate::error::ContractError;
 mod tests {
     // 테스트 모듈 선언
     pub mod integration_tests;
+    pub mod emission_tests;
+    #[allow(clippy::bool_assert_comparison)]
     pub mod fee_tests;
+    pub mod helpers;
+    pub mod htlc_tests;
+    pub mod reversible_tests;
+    pub mod scheduled_tests;
+    pub mod stream_tests;
+    pub mod vesting_tests;
 }
\ No newline at end of file
@@ -0,0 +1,200 @@
+use cosmwasm_std::{
+    Addr, BlockInfo, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Uint128,
+};
+use cw20::Expiration;
+use cw_utils::Duration;
+
+use crate::error::ContractError;
+use crate::msg::{NextTransferResponse, TransferLimitsResponse};
+use crate::state::{
+    LastTransfer, TransferLimits, BALANCES, EXTENDED_INFO, LAST_TRANSFER, TRANSFER_COOLDOWN,
+    TRANSFER_LIMITS,
+};
+
+fn is_exempt(limits: &TransferLimits, address: &Addr) -> bool {
+    limits.exempt.contains(address)
+}
+
+/// 1회 전송 한도. 면제 주소가 보내는 전송은 제외
+pub fn assert_max_tx(
+    storage: &dyn Storage,
+    sender: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let Some(limits) = TRANSFER_LIMITS.may_load(storage)? else {
+        return Ok(());
+    };
+    if let Some(max) = limits.max_tx_amount {
+        if amount > max && !is_exempt(&limits, sender) {
+            return Err(ContractError::ExceedsMaxTransaction { max });
+        }
+    }
+    Ok(())
+}
+
+/// 지갑 보유 한도. recipient가 received를 받은 뒤의 잔액으로 확인 (면제 주소 제외)
+pub fn assert_max_wallet(
+    storage: &dyn Storage,
+    recipient: &Addr,
+    received: Uint128,
+) -> Result<(), ContractError> {
+    let Some(limits) = TRANSFER_LIMITS.may_load(storage)? else {
+        return Ok(());
+    };
+    if let Some(max) = limits.max_wallet_balance {
+        if is_exempt(&limits, recipient) {
+            return Ok(());
+        }
+        let balance = BALANCES.may_load(storage, recipient)?.unwrap_or_default();
+        if balance + received > max {
+            return Err(ContractError::ExceedsMaxWalletBalance {
+                address: recipient.to_string(),
+                max,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// 전송 경로 공통 확인. amount는 보내는 총액, received는 수수료 차감 후 수신액
+pub fn assert_transfer_limits(
+    storage: &dyn Storage,
+    sender: &Addr,
+    recipient: &Addr,
+    amount: Uint128,
+    received: Uint128,
+) -> Result<(), ContractError> {
+    assert_max_tx(storage, sender, amount)?;
+    // 자기 자신에게 보내는 경우 잔액이 늘지 않는다
+    if sender != recipient {
+        assert_max_wallet(storage, recipient, received)?;
+    }
+    Ok(())
+}
+
+fn next_transfer_at(storage: &dyn Storage, sender: &Addr) -> StdResult<Option<Expiration>> {
+    let Some(cooldown) = TRANSFER_COOLDOWN.may_load(storage)? else {
+        return Ok(None);
+    };
+    let next = LAST_TRANSFER.may_load(storage, sender)?.map(|last| match cooldown {
+        Duration::Height(blocks) => Expiration::AtHeight(last.height + blocks),
+        Duration::Time(seconds) => Expiration::AtTime(last.time.plus_seconds(seconds)),
+    });
+    Ok(next)
+}
+
+/// 출금 쿨다운 확인 후 이번 출금 시점을 기록 (전송 한도 면제 주소도 쿨다운은 적용)
+pub fn record_outgoing_transfer(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    sender: &Addr,
+) -> Result<(), ContractError> {
+    if !TRANSFER_COOLDOWN.exists(storage) {
+        return Ok(());
+    }
+    if let Some(next) = next_transfer_at(storage, sender)? {
+        if !next.is_expired(block) {
+            return Err(ContractError::CooldownActive { next });
+        }
+    }
+    LAST_TRANSFER.save(
+        storage,
+        sender,
+        &LastTransfer {
+            height: block.height,
+            time: block.time,
+        },
+    )?;
+    Ok(())
+}
+
+pub fn execute_set_transfer_limits(
+    deps: DepsMut,
+    info: MessageInfo,
+    max_tx_amount: Option<Uint128>,
+    max_wallet_balance: Option<Uint128>,
+    exempt: Vec<String>,
+) -> Result<Response, ContractError> {
+    let extended_info = EXTENDED_INFO.load(deps.storage)?;
+    if info.sender != extended_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let exempt = exempt
+        .iter()
+        .map(|address| {
+            #[cfg(test)]
+            let addr = Addr::unchecked(address);
+            #[cfg(not(test))]
+            let addr = deps.api.addr_validate(address)?;
+            Ok(addr)
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    TRANSFER_LIMITS.save(
+        deps.storage,
+        &TransferLimits {
+            max_tx_amount,
+            max_wallet_balance,
+            exempt,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_transfer_limits")
+        .add_attribute(
+            "max_tx_amount",
+            max_tx_amount.map_or_else(|| "none".to_string(), |max| max.to_string()),
+        )
+        .add_attribute(
+            "max_wallet_balance",
+            max_wallet_balance.map_or_else(|| "none".to_string(), |max| max.to_string()),
+        ))
+}
+
+pub fn execute_set_transfer_cooldown(
+    deps: DepsMut,
+    info: MessageInfo,
+    cooldown: Option<Duration>,
+) -> Result<Response, ContractError> {
+    let extended_info = EXTENDED_INFO.load(deps.storage)?;
+    if info.sender != extended_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match cooldown {
+        Some(cooldown) => TRANSFER_COOLDOWN.save(deps.storage, &cooldown)?,
+        None => TRANSFER_COOLDOWN.remove(deps.storage),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_transfer_cooldown")
+        .add_attribute(
+            "cooldown",
+            cooldown.map_or_else(|| "none".to_string(), |cooldown| cooldown.to_string()),
+        ))
+}
+
+pub fn query_transfer_limits(deps: Deps) -> StdResult<TransferLimitsResponse> {
+    let limits = TRANSFER_LIMITS.may_load(deps.storage)?.unwrap_or_default();
+    Ok(TransferLimitsResponse {
+        max_tx_amount: limits.max_tx_amount,
+        max_wallet_balance: limits.max_wallet_balance,
+        exempt: limits.exempt.into_iter().map(Into::into).collect(),
+        cooldown: TRANSFER_COOLDOWN.may_load(deps.storage)?,
+    })
+}
+
+pub fn query_next_transfer(deps: Deps, env: Env, address: String) -> StdResult<NextTransferResponse> {
+    #[cfg(test)]
+    let addr = Addr::unchecked(&address);
+    #[cfg(not(test))]
+    let addr = deps.api.addr_validate(&address)?;
+
+    let next_allowed = next_transfer_at(deps.storage, &addr)?
+        .filter(|next| !next.is_expired(&env.block));
+    Ok(NextTransferResponse {
+        address,
+        next_allowed,
+    })
+}
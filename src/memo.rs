@@ -0,0 +1,95 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, MessageInfo, Response, StdResult, Storage,
+};
+
+use crate::error::ContractError;
+use crate::msg::{MemoInfo, MemoPayload, MemoRequiredResponse};
+use crate::state::{EXTENDED_INFO, MEMO_REQUIRED};
+
+/// 응답에 게임 메모 속성 추가
+pub fn add_memo_attributes(response: Response, memo: Option<&MemoInfo>) -> Response {
+    match memo {
+        Some(memo) => response
+            .add_attribute("memo_action", &memo.action)
+            .add_attribute("memo_player", &memo.player)
+            .add_attribute("memo_cost", &memo.cost),
+        None => response,
+    }
+}
+
+/// 메모가 있으면 검증 후 수신 컨트랙트에 전달할 메시지에 함께 담는다
+pub fn wrap_memo_msg(msg: Binary, memo: Option<&MemoInfo>) -> Result<Binary, ContractError> {
+    match memo {
+        Some(memo) => {
+            memo.validate()?;
+            Ok(to_json_binary(&MemoPayload {
+                memo: memo.clone(),
+                msg,
+            })?)
+        }
+        None => Ok(msg),
+    }
+}
+
+/// 메모 필수 주소로 보내는 전송에는 메모가 있어야 한다
+pub fn assert_memo_attached(
+    storage: &dyn Storage,
+    recipient: &Addr,
+    memo: Option<&MemoInfo>,
+) -> Result<(), ContractError> {
+    if memo.is_none() && MEMO_REQUIRED.has(storage, recipient) {
+        return Err(ContractError::MemoRequired {
+            recipient: recipient.to_string(),
+        });
+    }
+    Ok(())
+}
+
+// 본인 주소는 누구나, 다른 주소는 관리자만 메모 필수 여부를 설정할 수 있다
+pub fn execute_set_memo_required(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: Option<String>,
+    required: bool,
+) -> Result<Response, ContractError> {
+    let target = match address {
+        Some(address) => {
+            #[cfg(test)]
+            let addr = Addr::unchecked(&address);
+            #[cfg(not(test))]
+            let addr = deps.api.addr_validate(&address)?;
+            addr
+        }
+        None => info.sender.clone(),
+    };
+
+    if target != info.sender {
+        let extended_info = EXTENDED_INFO.load(deps.storage)?;
+        if info.sender != extended_info.admin {
+            return Err(ContractError::Unauthorized {});
+        }
+    }
+
+    if required {
+        MEMO_REQUIRED.save(deps.storage, &target, &true)?;
+    } else {
+        MEMO_REQUIRED.remove(deps.storage, &target);
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_memo_required")
+        .add_attribute("address", target)
+        .add_attribute("required", required.to_string()))
+}
+
+pub fn query_memo_required(deps: Deps, address: String) -> StdResult<MemoRequiredResponse> {
+    #[cfg(test)]
+    let addr = Addr::unchecked(&address);
+    #[cfg(not(test))]
+    let addr = deps.api.addr_validate(&address)?;
+
+    Ok(MemoRequiredResponse {
+        required: MEMO_REQUIRED.has(deps.storage, &addr),
+        address,
+    })
+}
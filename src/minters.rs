@@ -0,0 +1,454 @@
+use cosmwasm_std::{
+    Addr, BlockInfo, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Timestamp,
+    Uint128,
+};
+use cw20::Expiration;
+use cw_utils::Duration;
+
+use crate::error::ContractError;
+use crate::msg::{
+    MintCapacityResponse, MintEpochCapacity, MintRateLimitInfo, MinterResponse,
+    PendingMinterResponse,
+};
+use crate::state::{
+    MintEpochUsage, MintRateLimit, MinterData, MinterQuota, PendingMinter, CONFIG, EXTENDED_INFO,
+    GLOBAL_MINT_RATE_LIMIT, GLOBAL_MINT_USAGE, MINTERS, MINTER_MINT_USAGE, MINTER_RATE_LIMITS,
+    PENDING_MINTER, TOKEN_INFO,
+};
+
+/// 등록된 minter의 할당량에서 amount 차감. 미등록이면 Unauthorized
+pub fn consume_minter_quota(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    minter: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let mut quota = MINTERS
+        .may_load(storage, minter)?
+        .ok_or(ContractError::Unauthorized {})?;
+    if quota.expires.is_expired(block) {
+        return Err(ContractError::MinterExpired {});
+    }
+    let remaining = quota.remaining();
+    if amount > remaining {
+        return Err(ContractError::MinterQuotaExceeded { remaining });
+    }
+    quota.minted += amount;
+    MINTERS.save(storage, minter, &quota)?;
+    Ok(())
+}
+
+/// 기본 minter 변경. CONFIG.minter에도 반영하고 진행 중인 교체 제안은 무효화
+pub fn set_primary_minter(storage: &mut dyn Storage, mint: Option<MinterData>) -> StdResult<()> {
+    let mut token_info = TOKEN_INFO.load(storage)?;
+    token_info.mint = mint;
+    TOKEN_INFO.save(storage, &token_info)?;
+
+    let mut config = CONFIG.load(storage)?;
+    config.minter = token_info.mint.map(|mint| MinterResponse {
+        minter: mint.minter.into(),
+        cap: mint.cap,
+    });
+    CONFIG.save(storage, &config)?;
+
+    PENDING_MINTER.remove(storage);
+    Ok(())
+}
+
+fn assert_primary_minter(storage: &dyn Storage, sender: &Addr) -> Result<(), ContractError> {
+    let config = TOKEN_INFO.load(storage)?;
+    match config.mint {
+        Some(mint) if mint.minter == *sender => Ok(()),
+        _ => Err(ContractError::Unauthorized {}),
+    }
+}
+
+pub fn execute_propose_minter(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_minter: String,
+    expiry: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    assert_primary_minter(deps.storage, &info.sender)?;
+
+    let new_minter = deps.api.addr_validate(&new_minter)?;
+    let expires = expiry.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::InvalidExpiration {});
+    }
+
+    // 기존 제안이 있으면 덮어쓴다
+    PENDING_MINTER.save(
+        deps.storage,
+        &PendingMinter {
+            new_minter: new_minter.clone(),
+            expires,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_minter")
+        .add_attribute("new_minter", new_minter)
+        .add_attribute("expires", expires.to_string()))
+}
+
+pub fn execute_accept_minter(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_MINTER
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingMinter {})?;
+    if pending.new_minter != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    if pending.expires.is_expired(&env.block) {
+        return Err(ContractError::MinterProposalExpired {});
+    }
+
+    // cap은 그대로 유지하고 minter만 교체
+    let mint = TOKEN_INFO
+        .load(deps.storage)?
+        .mint
+        .ok_or(ContractError::NoMinter {})?;
+    set_primary_minter(
+        deps.storage,
+        Some(MinterData {
+            minter: pending.new_minter,
+            cap: mint.cap,
+        }),
+    )?;
+    let previous = mint.minter;
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_minter")
+        .add_attribute("previous_minter", previous)
+        .add_attribute("new_minter", info.sender))
+}
+
+pub fn execute_cancel_minter_proposal(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    assert_primary_minter(deps.storage, &info.sender)?;
+    let pending = PENDING_MINTER
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingMinter {})?;
+    PENDING_MINTER.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_minter_proposal")
+        .add_attribute("new_minter", pending.new_minter))
+}
+
+pub fn query_pending_minter(deps: Deps) -> StdResult<Option<PendingMinterResponse>> {
+    Ok(PENDING_MINTER
+        .may_load(deps.storage)?
+        .map(|pending| PendingMinterResponse {
+            new_minter: pending.new_minter.into(),
+            expires: pending.expires,
+        }))
+}
+
+pub fn execute_set_minter(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    allowance: Uint128,
+    expires: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let extended_info = EXTENDED_INFO.load(deps.storage)?;
+    if info.sender != extended_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    #[cfg(test)]
+    let minter = Addr::unchecked(&address);
+    #[cfg(not(test))]
+    let minter = deps.api.addr_validate(&address)?;
+
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::InvalidExpiration {});
+    }
+
+    // 할당량만 갱신하고 누적 발행량은 유지
+    let minted = MINTERS
+        .may_load(deps.storage, &minter)?
+        .map(|quota| quota.minted)
+        .unwrap_or_default();
+    MINTERS.save(
+        deps.storage,
+        &minter,
+        &MinterQuota {
+            allowance,
+            minted,
+            expires,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_minter")
+        .add_attribute("minter", minter)
+        .add_attribute("allowance", allowance)
+        .add_attribute("expires", expires.to_string()))
+}
+
+pub fn execute_remove_minter(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let extended_info = EXTENDED_INFO.load(deps.storage)?;
+    if info.sender != extended_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    #[cfg(test)]
+    let minter = Addr::unchecked(&address);
+    #[cfg(not(test))]
+    let minter = deps.api.addr_validate(&address)?;
+
+    MINTERS.remove(deps.storage, &minter);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_minter")
+        .add_attribute("minter", minter))
+}
+
+// 에포크는 블록 높이 또는 블록 시간을 epoch 길이로 나눈 구간 (height / n, seconds / n)
+fn epoch_index(epoch: &Duration, block: &BlockInfo) -> u64 {
+    match epoch {
+        Duration::Height(blocks) => block.height / blocks,
+        Duration::Time(seconds) => block.time.seconds() / seconds,
+    }
+}
+
+fn epoch_end(epoch: &Duration, index: u64) -> Expiration {
+    match epoch {
+        Duration::Height(blocks) => Expiration::AtHeight((index + 1) * blocks),
+        Duration::Time(seconds) => Expiration::AtTime(Timestamp::from_seconds((index + 1) * seconds)),
+    }
+}
+
+/// 현재 에포크의 사용량. 저장된 사용량이 지난 에포크 것이면 0부터 시작
+fn current_usage(
+    limit: &MintRateLimit,
+    usage: Option<MintEpochUsage>,
+    block: &BlockInfo,
+) -> MintEpochUsage {
+    let epoch = epoch_index(&limit.epoch, block);
+    match usage {
+        Some(usage) if usage.epoch == epoch => usage,
+        _ => MintEpochUsage {
+            epoch,
+            minted: Uint128::zero(),
+        },
+    }
+}
+
+fn to_capacity(limit: MintRateLimit, usage: MintEpochUsage) -> MintEpochCapacity {
+    MintEpochCapacity {
+        remaining: limit.max_per_epoch.saturating_sub(usage.minted),
+        resets_at: epoch_end(&limit.epoch, usage.epoch),
+        max_per_epoch: limit.max_per_epoch,
+        epoch: limit.epoch,
+        minted: usage.minted,
+    }
+}
+
+fn load_capacities(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    minter: Option<&Addr>,
+) -> StdResult<(Option<MintEpochCapacity>, Option<MintEpochCapacity>)> {
+    let global = match GLOBAL_MINT_RATE_LIMIT.may_load(storage)? {
+        Some(limit) => {
+            let usage = current_usage(&limit, GLOBAL_MINT_USAGE.may_load(storage)?, block);
+            Some(to_capacity(limit, usage))
+        }
+        None => None,
+    };
+    let minter = match minter {
+        Some(minter) => match MINTER_RATE_LIMITS.may_load(storage, minter)? {
+            Some(limit) => {
+                let usage =
+                    current_usage(&limit, MINTER_MINT_USAGE.may_load(storage, minter)?, block);
+                Some(to_capacity(limit, usage))
+            }
+            None => None,
+        },
+        None => None,
+    };
+    Ok((global, minter))
+}
+
+fn remaining_capacity(
+    global: Option<&MintEpochCapacity>,
+    minter: Option<&MintEpochCapacity>,
+) -> Option<Uint128> {
+    match (global, minter) {
+        (Some(global), Some(minter)) => Some(global.remaining.min(minter.remaining)),
+        (Some(capacity), None) | (None, Some(capacity)) => Some(capacity.remaining),
+        (None, None) => None,
+    }
+}
+
+/// 발행 권한 확인 후 할당량과 발행 속도 한도 차감.
+/// 기본 minter는 cap만 적용, 그 외에는 등록된 minter의 개별 할당량에서 차감
+pub fn authorize_mint(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    minter: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let is_primary_minter = TOKEN_INFO
+        .load(storage)?
+        .mint
+        .is_some_and(|mint| mint.minter == minter);
+    if !is_primary_minter {
+        consume_minter_quota(storage, block, minter, amount)?;
+    }
+    consume_mint_rate(storage, block, Some(minter), amount)
+}
+
+/// 현재 에포크에 남은 전체 발행 한도. 한도가 없으면 None
+pub fn global_mint_remaining(storage: &dyn Storage, block: &BlockInfo) -> StdResult<Option<Uint128>> {
+    let (global, _) = load_capacities(storage, block, None)?;
+    Ok(global.map(|global| global.remaining))
+}
+
+/// 전체 및 minter별 에포크 발행 한도 확인 후 사용량 기록.
+/// minter가 없는 발행(배출 등)은 전체 한도만 적용
+pub fn consume_mint_rate(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    minter: Option<&Addr>,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let (global, minter_capacity) = load_capacities(storage, block, minter)?;
+    if let Some(remaining) = remaining_capacity(global.as_ref(), minter_capacity.as_ref()) {
+        if amount > remaining {
+            return Err(ContractError::MintRateExceeded { remaining });
+        }
+    }
+
+    if let Some(global) = global {
+        let usage = MintEpochUsage {
+            epoch: epoch_index(&global.epoch, block),
+            minted: global.minted + amount,
+        };
+        GLOBAL_MINT_USAGE.save(storage, &usage)?;
+    }
+    if let (Some(minter), Some(capacity)) = (minter, minter_capacity) {
+        let usage = MintEpochUsage {
+            epoch: epoch_index(&capacity.epoch, block),
+            minted: capacity.minted + amount,
+        };
+        MINTER_MINT_USAGE.save(storage, minter, &usage)?;
+    }
+    Ok(())
+}
+
+/// 관리자 전용. minter 미지정 시 전체 한도, 지정 시 해당 minter의 한도 설정.
+/// limit이 None이면 한도 해제
+pub fn execute_set_mint_rate_limit(
+    deps: DepsMut,
+    info: MessageInfo,
+    minter: Option<String>,
+    limit: Option<MintRateLimitInfo>,
+) -> Result<Response, ContractError> {
+    let extended_info = EXTENDED_INFO.load(deps.storage)?;
+    if info.sender != extended_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(limit) = &limit {
+        if matches!(limit.epoch, Duration::Height(0) | Duration::Time(0)) {
+            return Err(ContractError::InvalidMintRateLimit {
+                msg: "epoch must be greater than zero".to_string(),
+            });
+        }
+    }
+    let limit = limit.map(|limit| MintRateLimit {
+        max_per_epoch: limit.max_per_epoch,
+        epoch: limit.epoch,
+    });
+
+    let minter = minter
+        .map(|address| -> StdResult<Addr> {
+            #[cfg(test)]
+            let addr = Addr::unchecked(&address);
+            #[cfg(not(test))]
+            let addr = deps.api.addr_validate(&address)?;
+            Ok(addr)
+        })
+        .transpose()?;
+
+    // 에포크 길이가 바뀌면 에포크 번호가 달라지므로 사용량 초기화
+    match &minter {
+        Some(minter) => {
+            let previous = MINTER_RATE_LIMITS.may_load(deps.storage, minter)?;
+            if previous.map(|p| p.epoch) != limit.as_ref().map(|l| l.epoch) {
+                MINTER_MINT_USAGE.remove(deps.storage, minter);
+            }
+            match &limit {
+                Some(limit) => MINTER_RATE_LIMITS.save(deps.storage, minter, limit)?,
+                None => MINTER_RATE_LIMITS.remove(deps.storage, minter),
+            }
+        }
+        None => {
+            let previous = GLOBAL_MINT_RATE_LIMIT.may_load(deps.storage)?;
+            if previous.map(|p| p.epoch) != limit.as_ref().map(|l| l.epoch) {
+                GLOBAL_MINT_USAGE.remove(deps.storage);
+            }
+            match &limit {
+                Some(limit) => GLOBAL_MINT_RATE_LIMIT.save(deps.storage, limit)?,
+                None => GLOBAL_MINT_RATE_LIMIT.remove(deps.storage),
+            }
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_mint_rate_limit")
+        .add_attribute(
+            "minter",
+            minter.map_or_else(|| "global".to_string(), Into::into),
+        )
+        .add_attribute(
+            "max_per_epoch",
+            limit
+                .as_ref()
+                .map_or_else(|| "none".to_string(), |l| l.max_per_epoch.to_string()),
+        )
+        .add_attribute(
+            "epoch",
+            limit.map_or_else(|| "none".to_string(), |l| l.epoch.to_string()),
+        ))
+}
+
+pub fn query_mint_capacity(
+    deps: Deps,
+    env: Env,
+    minter: Option<String>,
+) -> StdResult<MintCapacityResponse> {
+    let minter = minter
+        .map(|address| -> StdResult<Addr> {
+            #[cfg(test)]
+            let addr = Addr::unchecked(&address);
+            #[cfg(not(test))]
+            let addr = deps.api.addr_validate(&address)?;
+            Ok(addr)
+        })
+        .transpose()?;
+
+    let (global, minter) = load_capacities(deps.storage, &env.block, minter.as_ref())?;
+    Ok(MintCapacityResponse {
+        remaining: remaining_capacity(global.as_ref(), minter.as_ref()),
+        global,
+        minter,
+    })
+}
@@ -1,10 +1,16 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary, StdError, StdResult,Uint128};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, CosmosMsg, Decimal, HexBinary, StdError, StdResult, Timestamp, Uint128,
+    WasmMsg,
+};
 use cw20::{Cw20Coin, Expiration, Logo };
+use cw_utils::Duration;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::fee::{FeeTokenType, FeeType};
+use crate::error::ContractError;
+use crate::fee::{BatchFeeMode, FeeTokenType, FeeType};
+use crate::pause::PausableOperation;
 
 #[cw_serde]
 pub struct InstantiateMarketingInfo {
@@ -15,17 +21,103 @@ pub struct InstantiateMarketingInfo {
     pub logo_url_state: Option<String>,
 }
 
+// 게임 메모 필드 최대 길이 (bytes)
+pub const MAX_MEMO_FIELD_LEN: usize = 64;
+
 #[cw_serde]
 pub struct MemoInfo {
-    action: String,
-    player: String,
-    cost: String,
+    pub action: String,
+    pub player: String,
+    pub cost: String,
+}
+
+impl MemoInfo {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.action.trim().is_empty() || self.player.trim().is_empty() {
+            return Err(ContractError::InvalidMemo {
+                msg: "action and player must not be empty".to_string(),
+            });
+        }
+        for (field, value) in [
+            ("action", &self.action),
+            ("player", &self.player),
+            ("cost", &self.cost),
+        ] {
+            if value.len() > MAX_MEMO_FIELD_LEN {
+                return Err(ContractError::InvalidMemo {
+                    msg: format!("{} exceeds {} bytes", field, MAX_MEMO_FIELD_LEN),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// SendWithMemo 수신 컨트랙트에 전달되는 Cw20ReceiveMsg.msg 내용
+#[cw_serde]
+pub struct MemoPayload {
+    pub memo: MemoInfo,
+    pub msg: Binary,
+}
+
+/// 등록된 훅 컨트랙트에 전달되는 잔액 변경 내역. changes는 (주소, 이전 잔액, 새 잔액)
+#[cw_serde]
+pub struct BalanceChangeHookMsg {
+    pub changes: Vec<(String, Uint128, Uint128)>,
+}
+
+impl BalanceChangeHookMsg {
+    pub fn into_json_binary(self) -> StdResult<Binary> {
+        to_json_binary(&BalanceChangeExecuteMsg::BalanceChangeHook(self))
+    }
+
+    pub fn into_cosmos_msg<T: Into<String>>(self, contract_addr: T) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: contract_addr.into(),
+            msg: self.into_json_binary()?,
+            funds: vec![],
+        }
+        .into())
+    }
+}
+
+/// 훅 컨트랙트가 구현해야 하는 실행 메시지
+#[cw_serde]
+enum BalanceChangeExecuteMsg {
+    BalanceChangeHook(BalanceChangeHookMsg),
 }
 
 #[cw_serde]
 pub struct InitialBalance {
     pub address: String,
     pub amount: Uint128,
+    /// 지정 시 amount 전체가 이 스케줄에 따라 잠긴다
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vesting: Option<VestingSchedule>,
+}
+
+/// 베스팅 스케줄. start부터 end까지 선형으로 풀리며 cliff 이전에는 전액 잠김
+#[cw_serde]
+pub struct VestingSchedule {
+    pub start: Timestamp,
+    pub cliff: Timestamp,
+    pub end: Timestamp,
+}
+
+impl VestingSchedule {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.start >= self.end {
+            return Err(ContractError::InvalidVestingSchedule {
+                msg: "start must be before end".to_string(),
+            });
+        }
+        if self.cliff < self.start || self.cliff > self.end {
+            return Err(ContractError::InvalidVestingSchedule {
+                msg: "cliff must be between start and end".to_string(),
+            });
+        }
+        Ok(())
+    }
 }
 
 #[cw_serde]
@@ -39,7 +131,7 @@ pub struct InstantiateMsg {
     pub name: String,
     pub symbol: String,
     pub decimals: u8,
-    pub initial_balances: Vec<Cw20Coin>,
+    pub initial_balances: Vec<InitialBalance>,
     pub mint: Option<MinterResponse>,
     pub marketing: Option<InstantiateMarketingInfo>,
     pub created_on_platform: Option<String>,
@@ -58,6 +150,7 @@ pub struct FeeConfigResponse {
     pub token_type: FeeTokenType,
     pub collectors: Vec<FeeCollectorResponse>,
     pub is_active: bool,
+    pub batch_fee_mode: BatchFeeMode,
 }
 
 #[cw_serde]
@@ -120,16 +213,39 @@ impl InstantiateMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     Transfer { recipient: String, amount: Uint128 },
+    /// 여러 수신자에게 한 번에 전송. 수수료는 FeeConfig의 batch_fee_mode에 따라 적용
+    BatchTransfer { transfers: Vec<BatchTransferItem> },
     Burn { amount: Uint128 },
+    /// 소각 후 사유와 외부 참조(주문 ID, 브리지 목적지 등)를 영수증으로 저장
+    BurnWithReason {
+        amount: Uint128,
+        reason: String,
+        reference: Option<String>,
+    },
     Send {
         contract: String,
         amount: Uint128,
         msg: Binary,
        },
+    /// 게임 메모(MemoInfo)를 포함한 전송
+    TransferWithMemo {
+        recipient: String,
+        amount: Uint128,
+        memo: MemoInfo,
+    },
+    /// 게임 메모를 포함한 Send. 수신 컨트랙트에는 MemoPayload { memo, msg }가 전달된다
+    SendWithMemo {
+        contract: String,
+        amount: Uint128,
+        msg: Binary,
+        memo: MemoInfo,
+    },
     IncreaseAllowance {
          spender: String,
          amount: Uint128,
          expires: Option<Expiration>,
+         /// 지정 시 spender가 이 주소들에게만 전송 가능하고 소각은 불가 (빈 목록은 제한 해제)
+         allowed_recipients: Option<Vec<String>>,
     },
     DecreaseAllowance {
         spender: String,
@@ -148,9 +264,120 @@ pub enum ExecuteMsg {
         msg: Binary,
     },
        
+    TransferFromWithMemo {
+        owner: String,
+        recipient: String,
+        amount: Uint128,
+        memo: MemoInfo,
+    },
+    SendFromWithMemo {
+        owner: String,
+        contract: String,
+        amount: Uint128,
+        msg: Binary,
+        memo: MemoInfo,
+    },
     BurnFrom { owner: String, amount: Uint128 },
+    /// 토큰을 에스크로에 넣고 release_at 이후 recipient에게 지급.
+    /// cancellable_until 전까지는 sender가 취소 가능 (미지정 시 취소 불가)
+    ScheduleTransfer {
+        recipient: String,
+        amount: Uint128,
+        release_at: Expiration,
+        cancellable_until: Option<Expiration>,
+    },
+    /// release_at이 지난 예약 전송 지급 (누구나 호출 가능)
+    ReleaseScheduled { id: u64 },
+    CancelScheduled { id: u64 },
+    /// hashlock(preimage의 sha256)으로 잠근 에스크로 전송. timelock 전까지 preimage로 청구 가능
+    LockHtlc {
+        recipient: String,
+        amount: Uint128,
+        hashlock: HexBinary,
+        timelock: Expiration,
+    },
+    /// preimage 확인 후 recipient에게 지급 (누구나 호출 가능)
+    ClaimHtlc { id: u64, preimage: HexBinary },
+    /// timelock이 지난 HTLC를 sender에게 환불 (누구나 호출 가능)
+    RefundHtlc { id: u64 },
+    /// 되돌릴 수 있는 전송. 되돌림 기간 동안 recipient 잔액에 반영되지 않고 대기한다
+    ReversibleTransfer { recipient: String, amount: Uint128 },
+    /// 되돌림 기간 내 sender가 전액 회수
+    ReverseTransfer { id: u64 },
+    /// 되돌림 기간이 지난 전송을 recipient가 수령 (recipient의 다음 실행 시에도 자동 확정)
+    AcceptTransfer { id: u64 },
+    /// start부터 end 블록 높이까지 recipient에게 total을 선형으로 지급하는 스트림 생성 (sender 잔액에서 에스크로)
+    CreateStream {
+        recipient: String,
+        total: Uint128,
+        start: u64,
+        end: u64,
+    },
+    /// recipient가 지금까지 쌓인 양을 인출
+    WithdrawStream { id: u64 },
+    /// sender가 스트림 취소. 쌓인 양은 recipient에게, 나머지는 sender에게 반환
+    CancelStream { id: u64 },
+    /// 여러 spender의 허용량을 한 번에 갱신 (하나라도 실패하면 전체 취소)
+    BatchAllowances { updates: Vec<AllowanceUpdate> },
+    /// recipient에게 베스팅 토큰 지급 (주소당 여러 건 가능). mint가 true면 minter가 신규 발행
+    /// (Mint와 같은 권한과 한도 적용), 아니면 관리자가 자기 잔액에서 이동
+    GrantVested {
+        recipient: String,
+        amount: Uint128,
+        schedule: VestingSchedule,
+        mint: bool,
+    },
+    /// operator가 만료 전까지 sender의 토큰을 한도 없이 이동할 수 있도록 승인
+    ApproveOperator {
+        operator: String,
+        expires: Option<Expiration>,
+    },
+    RevokeOperator { operator: String },
     Mint { recipient: String, amount: Uint128 },
-    UpdateMinter { new_minter: Option<String> },
+    /// 여러 수신자에게 한 번에 발행. 권한, 할당량, 속도 제한, cap은 총액 기준으로 한 번만 확인
+    BatchMint { mints: Vec<Cw20Coin> },
+    /// 기본 minter를 즉시 교체하거나 제거. 주소 오입력을 막기 위해 force 필수,
+    /// 일반적인 교체는 ProposeMinter / AcceptMinter 사용
+    UpdateMinter {
+        new_minter: Option<String>,
+        #[serde(default)]
+        force: bool,
+    },
+    /// 기본 minter 전용. new_minter가 만료 전에 AcceptMinter를 호출하면 교체된다
+    ProposeMinter {
+        new_minter: String,
+        expiry: Option<Expiration>,
+    },
+    /// 제안받은 주소만 호출 가능
+    AcceptMinter {},
+    /// 기본 minter 전용
+    CancelMinterProposal {},
+    /// 관리자 전용. 추가 minter 등록 또는 할당량 갱신 (누적 발행량은 유지)
+    SetMinter {
+        address: String,
+        allowance: Uint128,
+        expires: Option<Expiration>,
+    },
+    /// 관리자 전용
+    RemoveMinter { address: String },
+    /// 관리자 전용. 에포크당 최대 발행량 설정. minter 미지정 시 전체 한도,
+    /// limit이 없으면 한도 해제
+    SetMintRateLimit {
+        minter: Option<String>,
+        limit: Option<MintRateLimitInfo>,
+    },
+    /// 관리자 전용. 최대 발행량을 낮춘다 (올리거나 해제할 수 없음)
+    LowerMaxSupply { max_supply: Uint128 },
+    /// 관리자 전용. 발행 스케줄 설정 (start_height 미지정 시 현재 블록부터).
+    /// 기존 스케줄로 쌓인 미지급분은 다음 ReleaseEmissions에서 함께 발행된다
+    SetEmissionSchedule {
+        rate_per_block: Uint128,
+        start_height: Option<u64>,
+        halving_interval: Option<u64>,
+        recipients: Vec<EmissionRecipient>,
+    },
+    /// 마지막 지급 이후 쌓인 발행량을 수령 주소들에 발행 (누구나 호출 가능, cap 이내)
+    ReleaseEmissions {},
     UpdateMarketing {
             project: Option<String>,
             description: Option<String>,
@@ -158,6 +385,47 @@ pub enum ExecuteMsg {
     },
      
     UploadLogo(Logo),
+    /// 주소를 메모 필수로 등록/해제. address 미지정 시 sender 본인, 다른 주소는 관리자만 가능
+    SetMemoRequired {
+        address: Option<String>,
+        required: bool,
+    },
+    /// 관리자 전용. 동결된 주소는 owner나 spender로서 토큰을 보내거나 받을 수 없다
+    FreezeAccount {
+        address: String,
+        reason: String,
+    },
+    UnfreezeAccount {
+        address: String,
+    },
+    /// 관리자 전용. 1회 전송 한도, 지갑 보유 한도 설정
+    /// (None은 제한 없음, exempt는 전체 교체)
+    SetTransferLimits {
+        max_tx_amount: Option<Uint128>,
+        max_wallet_balance: Option<Uint128>,
+        exempt: Vec<String>,
+    },
+    /// 관리자 전용. 같은 주소의 연속 출금 사이 최소 간격 설정 (None은 해제, 면제 주소 없음)
+    SetTransferCooldown {
+        cooldown: Option<Duration>,
+    },
+    /// 관리자 전용. 잔액 변경 훅 컨트랙트 등록. revert_on_error가 true면 훅 실패 시 원래 실행도 실패
+    AddHook {
+        address: String,
+        revert_on_error: bool,
+    },
+    /// 관리자 전용
+    RemoveHook { address: String },
+    /// 관리자 전용. 되돌릴 수 있는 전송의 대기 기간 설정
+    SetReversibleWindow { window: Duration },
+    /// 관리자 전용. operations 미지정 시 전체 정지
+    Pause {
+        operations: Option<Vec<PausableOperation>>,
+    },
+    /// 관리자 전용. operations 미지정 시 전체 해제
+    Unpause {
+        operations: Option<Vec<PausableOperation>>,
+    },
     SetFeeGranter {
         address: Option<String>,
     },
@@ -172,6 +440,49 @@ pub enum ExecuteMsg {
         token_type: FeeTokenType,
         collectors: Vec<FeeCollectorInput>,
         is_active: bool,
+        /// 미지정 시 PerTransfer
+        batch_fee_mode: Option<BatchFeeMode>,
+    },
+}
+
+#[cw_serde]
+pub struct MintRateLimitInfo {
+    pub max_per_epoch: Uint128,
+    /// 에포크 길이 (블록 수 또는 초)
+    pub epoch: Duration,
+}
+
+#[cw_serde]
+pub struct EmissionRecipient {
+    pub address: String,
+    /// 발행량 중 수령 비율 (0 초과 1 이하, 전체 합계 1)
+    pub share: Decimal,
+}
+
+#[cw_serde]
+pub struct BatchTransferItem {
+    pub recipient: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum AllowanceUpdate {
+    Increase {
+        spender: String,
+        amount: Uint128,
+        expires: Option<Expiration>,
+        allowed_recipients: Option<Vec<String>>,
+    },
+    Decrease {
+        spender: String,
+        amount: Uint128,
+        expires: Option<Expiration>,
+    },
+    /// 허용량을 amount로 정확히 설정 (0이면 삭제)
+    Set {
+        spender: String,
+        amount: Uint128,
+        expires: Option<Expiration>,
     },
 }
 
@@ -182,20 +493,28 @@ pub enum QueryMsg {
     TokenInfo {},
     #[returns(cw20::BalanceResponse)]
     Balance { address: String },
-    #[returns(cw20::AllowanceResponse)]
+    #[returns(AllowanceResponse)]
     Allowance { owner: String, spender: String },
-    #[returns(cw20::AllAllowancesResponse)]
+    #[returns(AllAllowancesResponse)]
     AllAllowances {
         owner: String,
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    #[returns(OperatorsResponse)]
+    Operators {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(IsOperatorResponse)]
+    IsOperator { owner: String, operator: String },
     #[returns(cw20::AllAccountsResponse)]
     AllAccounts {
         start_after: Option<String>,
         limit: Option<u32>,
     },
-    #[returns(cw20::AllSpenderAllowancesResponse)]
+    #[returns(AllSpenderAllowancesResponse)]
     AllSpenderAllowances {
         spender: String,
         start_after: Option<String>,
@@ -210,10 +529,94 @@ pub enum QueryMsg {
     FeeGranter {},
     #[returns(Option<MinterResponse>)]
     Minter {},
+    /// 진행 중인 기본 minter 교체 제안
+    #[returns(Option<PendingMinterResponse>)]
+    PendingMinter {},
+    /// 관리자가 등록한 추가 minter와 할당량 목록
+    #[returns(MintersResponse)]
+    Minters {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// 현재 에포크에서 남은 발행 가능량. minter 지정 시 해당 minter 한도도 반영
+    #[returns(MintCapacityResponse)]
+    MintCapacity { minter: Option<String> },
     #[returns(TotalSupplyResponse)]
     TotalSupply {},
+    /// height 블록 시작 시점의 총 발행량
+    #[returns(TotalSupplyAtHeightResponse)]
+    TotalSupplyAtHeight { height: u64 },
+    /// 최대 발행량과 잠금 여부
+    #[returns(SupplyCapResponse)]
+    SupplyCap {},
     #[returns(FeeConfigResponse)]
     FeeConfig {},
+    #[returns(ScheduledTransferResponse)]
+    ScheduledTransfer { id: u64 },
+    #[returns(HtlcResponse)]
+    Htlc { id: u64 },
+    #[returns(HtlcsResponse)]
+    HtlcsBySender {
+        sender: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(HtlcsResponse)]
+    HtlcsByRecipient {
+        recipient: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(PendingTransferResponse)]
+    PendingTransfer { id: u64 },
+    /// recipient 앞으로 대기 중인 되돌릴 수 있는 전송 목록
+    #[returns(PendingTransfersResponse)]
+    PendingTransfers {
+        recipient: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(BurnReceiptResponse)]
+    BurnReceipt { id: u64 },
+    #[returns(BurnReceiptsResponse)]
+    BurnReceiptsByBurner {
+        burner: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(StreamResponse)]
+    Stream { id: u64 },
+    #[returns(StreamsResponse)]
+    StreamsBySender {
+        sender: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(StreamsResponse)]
+    StreamsByRecipient {
+        recipient: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(MemoRequiredResponse)]
+    MemoRequired { address: String },
+    #[returns(VestingInfoResponse)]
+    VestingInfo { address: String },
+    #[returns(TransferLimitsResponse)]
+    TransferLimits {},
+    #[returns(NextTransferResponse)]
+    NextTransfer { address: String },
+    #[returns(PauseStatusResponse)]
+    PauseStatus {},
+    #[returns(HooksResponse)]
+    Hooks {},
+    #[returns(EmissionInfoResponse)]
+    EmissionInfo {},
+    #[returns(FrozenAccountsResponse)]
+    FrozenAccounts {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -232,6 +635,277 @@ pub struct TotalSupplyResponse {
     pub total_supply: Uint128,
 }
 
+#[cw_serde]
+pub struct TotalSupplyAtHeightResponse {
+    pub height: u64,
+    /// 기록 시작 이전 높이는 None
+    pub total_supply: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct SupplyCapResponse {
+    pub max_supply: Option<Uint128>,
+    pub total_supply: Uint128,
+    pub remaining: Option<Uint128>,
+    /// 최대 발행량이 설정되어 더 이상 올리거나 해제할 수 없는지 여부
+    pub locked: bool,
+}
+
+// cw20 허용량 응답에 허용 수신자 목록을 추가한 구조체들.
+// 제한이 없는 허용량은 cw20 응답과 동일하게 직렬화된다.
+#[cw_serde]
+#[derive(Default)]
+pub struct AllowanceResponse {
+    pub allowance: Uint128,
+    pub expires: Expiration,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_recipients: Option<Vec<String>>,
+}
+
+#[cw_serde]
+pub struct AllowanceInfo {
+    pub spender: String,
+    pub allowance: Uint128,
+    pub expires: Expiration,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_recipients: Option<Vec<String>>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct AllAllowancesResponse {
+    pub allowances: Vec<AllowanceInfo>,
+}
+
+#[cw_serde]
+pub struct SpenderAllowanceInfo {
+    pub owner: String,
+    pub allowance: Uint128,
+    pub expires: Expiration,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_recipients: Option<Vec<String>>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct AllSpenderAllowancesResponse {
+    pub allowances: Vec<SpenderAllowanceInfo>,
+}
+
+#[cw_serde]
+pub struct OperatorInfo {
+    pub operator: String,
+    pub expires: Expiration,
+}
+
+#[cw_serde]
+pub struct OperatorsResponse {
+    pub operators: Vec<OperatorInfo>,
+}
+
+#[cw_serde]
+pub struct IsOperatorResponse {
+    pub is_operator: bool,
+}
+
+#[cw_serde]
+pub struct ScheduledTransferResponse {
+    pub id: u64,
+    pub sender: String,
+    pub recipient: String,
+    pub amount: Uint128,
+    pub release_at: Expiration,
+    pub cancellable_until: Option<Expiration>,
+}
+
+#[cw_serde]
+pub struct HtlcResponse {
+    pub id: u64,
+    pub sender: String,
+    pub recipient: String,
+    pub amount: Uint128,
+    pub hashlock: HexBinary,
+    pub timelock: Expiration,
+}
+
+#[cw_serde]
+pub struct HtlcsResponse {
+    pub htlcs: Vec<HtlcResponse>,
+}
+
+#[cw_serde]
+pub struct PendingTransferResponse {
+    pub id: u64,
+    pub sender: String,
+    pub recipient: String,
+    pub amount: Uint128,
+    pub settle_at: Expiration,
+}
+
+#[cw_serde]
+pub struct PendingTransfersResponse {
+    pub transfers: Vec<PendingTransferResponse>,
+}
+
+#[cw_serde]
+pub struct BurnReceiptResponse {
+    pub id: u64,
+    pub burner: String,
+    pub amount: Uint128,
+    pub reason: String,
+    pub reference: Option<String>,
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+#[cw_serde]
+pub struct BurnReceiptsResponse {
+    pub receipts: Vec<BurnReceiptResponse>,
+}
+
+#[cw_serde]
+pub struct StreamResponse {
+    pub id: u64,
+    pub sender: String,
+    pub recipient: String,
+    pub total: Uint128,
+    pub withdrawn: Uint128,
+    /// 현재 블록 기준 인출 가능한 양
+    pub withdrawable: Uint128,
+    pub start_height: u64,
+    pub end_height: u64,
+}
+
+#[cw_serde]
+pub struct StreamsResponse {
+    pub streams: Vec<StreamResponse>,
+}
+
+#[cw_serde]
+pub struct MemoRequiredResponse {
+    pub address: String,
+    pub required: bool,
+}
+
+#[cw_serde]
+pub struct VestingInfoResponse {
+    pub address: String,
+    /// 베스팅 총량 (스케줄이 없으면 0)
+    pub total: Uint128,
+    pub vested: Uint128,
+    pub locked: Uint128,
+    /// 풀린 물량 중 계정에서 빠져나간 양
+    pub claimed: Uint128,
+    /// 지급 건별 내역 (지급 순)
+    pub grants: Vec<VestingGrantInfo>,
+}
+
+#[cw_serde]
+pub struct VestingGrantInfo {
+    pub total: Uint128,
+    pub vested: Uint128,
+    pub schedule: VestingSchedule,
+}
+
+#[cw_serde]
+pub struct TransferLimitsResponse {
+    pub max_tx_amount: Option<Uint128>,
+    pub max_wallet_balance: Option<Uint128>,
+    pub exempt: Vec<String>,
+    pub cooldown: Option<Duration>,
+}
+
+#[cw_serde]
+pub struct NextTransferResponse {
+    pub address: String,
+    /// 쿨다운이 끝나는 시점. None이면 지금 바로 출금 가능
+    pub next_allowed: Option<Expiration>,
+}
+
+#[cw_serde]
+pub struct PauseStatusResponse {
+    pub paused_operations: Vec<PausableOperation>,
+    pub fully_paused: bool,
+}
+
+#[cw_serde]
+pub struct PendingMinterResponse {
+    pub new_minter: String,
+    pub expires: Expiration,
+}
+
+#[cw_serde]
+pub struct MinterInfo {
+    pub address: String,
+    pub allowance: Uint128,
+    pub minted: Uint128,
+    pub remaining: Uint128,
+    pub expires: Expiration,
+}
+
+#[cw_serde]
+pub struct MintersResponse {
+    pub minters: Vec<MinterInfo>,
+}
+
+#[cw_serde]
+pub struct MintEpochCapacity {
+    pub max_per_epoch: Uint128,
+    pub epoch: Duration,
+    pub minted: Uint128,
+    pub remaining: Uint128,
+    pub resets_at: Expiration,
+}
+
+#[cw_serde]
+pub struct MintCapacityResponse {
+    pub global: Option<MintEpochCapacity>,
+    pub minter: Option<MintEpochCapacity>,
+    /// 두 한도 중 작은 값. 한도가 없으면 None
+    pub remaining: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct FrozenAccountInfo {
+    pub address: String,
+    pub reason: String,
+}
+
+#[cw_serde]
+pub struct EmissionScheduleResponse {
+    pub rate_per_block: Uint128,
+    pub start_height: u64,
+    pub halving_interval: Option<u64>,
+    pub recipients: Vec<EmissionRecipient>,
+}
+
+#[cw_serde]
+pub struct EmissionInfoResponse {
+    pub schedule: Option<EmissionScheduleResponse>,
+    /// 현재 블록의 블록당 발행량 (반감 반영)
+    pub current_rate: Uint128,
+    pub last_release_height: u64,
+    /// 지금 ReleaseEmissions를 호출하면 발행되는 양 (cap 반영)
+    pub next_amount: Uint128,
+    pub total_released: Uint128,
+}
+
+#[cw_serde]
+pub struct HookInfo {
+    pub address: String,
+    pub revert_on_error: bool,
+}
+
+#[cw_serde]
+pub struct HooksResponse {
+    pub hooks: Vec<HookInfo>,
+}
+
+#[cw_serde]
+pub struct FrozenAccountsResponse {
+    pub accounts: Vec<FrozenAccountInfo>,
+}
+
 #[cw_serde]
 pub struct FeeGranterResponse {
     pub fee_granter: Option<String>,
@@ -0,0 +1,213 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Deps, DepsMut, MessageInfo, Response, StdResult, Storage};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, PauseStatusResponse};
+use crate::state::{EXTENDED_INFO, FEE_CONFIG, PAUSED_OPERATIONS};
+
+/// 일시 정지 가능한 작업 단위
+#[cw_serde]
+pub enum PausableOperation {
+    Transfer,
+    Send,
+    Mint,
+    Burn,
+    /// 허용량/운영자 승인 변경 및 허용량 기반 전송
+    Allowances,
+    /// 수수료 수취인 지급 (수수료 설정이 활성화된 경우에만 적용)
+    FeePayouts,
+}
+
+impl PausableOperation {
+    pub fn all() -> Vec<PausableOperation> {
+        vec![
+            PausableOperation::Transfer,
+            PausableOperation::Send,
+            PausableOperation::Mint,
+            PausableOperation::Burn,
+            PausableOperation::Allowances,
+            PausableOperation::FeePayouts,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PausableOperation::Transfer => "transfer",
+            PausableOperation::Send => "send",
+            PausableOperation::Mint => "mint",
+            PausableOperation::Burn => "burn",
+            PausableOperation::Allowances => "allowances",
+            PausableOperation::FeePayouts => "fee_payouts",
+        }
+    }
+}
+
+/// 메시지가 수행하는 작업 목록. 관리자 메시지는 빈 목록 (정지 중에도 실행 가능)
+fn operations_for(msg: &ExecuteMsg) -> Vec<PausableOperation> {
+    use PausableOperation::*;
+
+    match msg {
+        ExecuteMsg::Transfer { .. }
+        | ExecuteMsg::TransferWithMemo { .. }
+        | ExecuteMsg::BatchTransfer { .. }
+        | ExecuteMsg::ReleaseScheduled { .. } => vec![Transfer, FeePayouts],
+        ExecuteMsg::ClaimHtlc { .. }
+        | ExecuteMsg::AcceptTransfer { .. }
+        | ExecuteMsg::WithdrawStream { .. }
+        | ExecuteMsg::CancelStream { .. } => vec![Transfer, FeePayouts],
+        ExecuteMsg::ScheduleTransfer { .. }
+        | ExecuteMsg::CancelScheduled { .. }
+        | ExecuteMsg::ReversibleTransfer { .. }
+        | ExecuteMsg::ReverseTransfer { .. }
+        | ExecuteMsg::LockHtlc { .. }
+        | ExecuteMsg::CreateStream { .. }
+        | ExecuteMsg::RefundHtlc { .. } => vec![Transfer],
+        ExecuteMsg::Send { .. } | ExecuteMsg::SendWithMemo { .. } => vec![Send, FeePayouts],
+        ExecuteMsg::Mint { .. }
+        | ExecuteMsg::BatchMint { .. }
+        | ExecuteMsg::ReleaseEmissions {} => vec![Mint],
+        ExecuteMsg::GrantVested { mint, .. } => {
+            if *mint {
+                vec![Mint]
+            } else {
+                vec![Transfer]
+            }
+        }
+        ExecuteMsg::Burn { .. } | ExecuteMsg::BurnWithReason { .. } => vec![Burn],
+        ExecuteMsg::IncreaseAllowance { .. }
+        | ExecuteMsg::DecreaseAllowance { .. }
+        | ExecuteMsg::BatchAllowances { .. }
+        | ExecuteMsg::ApproveOperator { .. }
+        | ExecuteMsg::RevokeOperator { .. } => vec![Allowances],
+        ExecuteMsg::TransferFrom { .. } | ExecuteMsg::TransferFromWithMemo { .. } => {
+            vec![Allowances, Transfer, FeePayouts]
+        }
+        ExecuteMsg::SendFrom { .. } | ExecuteMsg::SendFromWithMemo { .. } => {
+            vec![Allowances, Send, FeePayouts]
+        }
+        ExecuteMsg::BurnFrom { .. } => vec![Allowances, Burn],
+        ExecuteMsg::UpdateMarketing { .. }
+        | ExecuteMsg::UploadLogo(_)
+        | ExecuteMsg::UpdateMinter { .. }
+        | ExecuteMsg::ProposeMinter { .. }
+        | ExecuteMsg::AcceptMinter {}
+        | ExecuteMsg::CancelMinterProposal {}
+        | ExecuteMsg::SetMinter { .. }
+        | ExecuteMsg::RemoveMinter { .. }
+        | ExecuteMsg::SetMintRateLimit { .. }
+        | ExecuteMsg::LowerMaxSupply { .. }
+        | ExecuteMsg::SetEmissionSchedule { .. }
+        | ExecuteMsg::SetMemoRequired { .. }
+        | ExecuteMsg::FreezeAccount { .. }
+        | ExecuteMsg::UnfreezeAccount { .. }
+        | ExecuteMsg::SetTransferLimits { .. }
+        | ExecuteMsg::SetTransferCooldown { .. }
+        | ExecuteMsg::SetReversibleWindow { .. }
+        | ExecuteMsg::AddHook { .. }
+        | ExecuteMsg::RemoveHook { .. }
+        | ExecuteMsg::Pause { .. }
+        | ExecuteMsg::Unpause { .. }
+        | ExecuteMsg::SetFeeGranter { .. }
+        | ExecuteMsg::SetUpgradeAdmin { .. }
+        | ExecuteMsg::UpdateConfig { .. }
+        | ExecuteMsg::SetFeeConfig { .. } => vec![],
+    }
+}
+
+/// execute 진입 시 메시지가 수행하는 작업 중 정지된 것이 있으면 거부
+pub fn assert_not_paused(storage: &dyn Storage, msg: &ExecuteMsg) -> Result<(), ContractError> {
+    assert_operations_allowed(storage, operations_for(msg))
+}
+
+pub fn assert_operations_allowed(
+    storage: &dyn Storage,
+    operations: Vec<PausableOperation>,
+) -> Result<(), ContractError> {
+    let paused = PAUSED_OPERATIONS.may_load(storage)?.unwrap_or_default();
+    if paused.is_empty() {
+        return Ok(());
+    }
+
+    for operation in operations {
+        if !paused.contains(&operation) {
+            continue;
+        }
+        if operation == PausableOperation::FeePayouts {
+            let fee_active = FEE_CONFIG
+                .may_load(storage)?
+                .is_some_and(|config| config.is_active);
+            if !fee_active {
+                continue;
+            }
+        }
+        return Err(ContractError::Paused {
+            operation: operation.as_str().to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// operations 미지정 시 전체 정지
+pub fn execute_pause(
+    deps: DepsMut,
+    info: MessageInfo,
+    operations: Option<Vec<PausableOperation>>,
+) -> Result<Response, ContractError> {
+    let extended_info = EXTENDED_INFO.load(deps.storage)?;
+    if info.sender != extended_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut paused = PAUSED_OPERATIONS.may_load(deps.storage)?.unwrap_or_default();
+    for operation in operations.unwrap_or_else(PausableOperation::all) {
+        if !paused.contains(&operation) {
+            paused.push(operation);
+        }
+    }
+    PAUSED_OPERATIONS.save(deps.storage, &paused)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "pause")
+        .add_attribute("paused", join_operations(&paused)))
+}
+
+/// operations 미지정 시 전체 해제
+pub fn execute_unpause(
+    deps: DepsMut,
+    info: MessageInfo,
+    operations: Option<Vec<PausableOperation>>,
+) -> Result<Response, ContractError> {
+    let extended_info = EXTENDED_INFO.load(deps.storage)?;
+    if info.sender != extended_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut paused = PAUSED_OPERATIONS.may_load(deps.storage)?.unwrap_or_default();
+    match operations {
+        Some(operations) => paused.retain(|operation| !operations.contains(operation)),
+        None => paused.clear(),
+    }
+    PAUSED_OPERATIONS.save(deps.storage, &paused)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "unpause")
+        .add_attribute("paused", join_operations(&paused)))
+}
+
+fn join_operations(operations: &[PausableOperation]) -> String {
+    operations
+        .iter()
+        .map(|operation| operation.as_str())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub fn query_pause_status(deps: Deps) -> StdResult<PauseStatusResponse> {
+    let paused = PAUSED_OPERATIONS.may_load(deps.storage)?.unwrap_or_default();
+    Ok(PauseStatusResponse {
+        fully_paused: PausableOperation::all()
+            .iter()
+            .all(|operation| paused.contains(operation)),
+        paused_operations: paused,
+    })
+}
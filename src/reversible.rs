@@ -0,0 +1,308 @@
+use cosmwasm_std::{
+    Addr, Deps, DepsMut, Env, Event, MessageInfo, Order, Response, StdResult, SubMsg, Uint128,
+};
+use cw_storage_plus::Bound;
+use cw_utils::Duration;
+
+use crate::error::ContractError;
+use crate::escrow::{debit_escrow, pay_from_escrow};
+use crate::fee::{add_fee_attributes, FeeCalculationResult};
+use crate::freeze::assert_not_frozen;
+use crate::hooks::balance_change_hooks;
+use crate::limits::assert_max_tx;
+use crate::memo::assert_memo_attached;
+use crate::msg::{ExecuteMsg, PendingTransferResponse, PendingTransfersResponse};
+use crate::pause::{assert_operations_allowed, PausableOperation};
+use crate::state::{
+    pending_transfers, PendingTransfer, AUTO_SETTLE_CURSOR, BALANCES, EXTENDED_INFO, FEE_CONFIG,
+    PENDING_TRANSFER_COUNT, REVERSIBLE_WINDOW,
+};
+
+
+// 되돌림 기간 기본값 (24시간)
+pub const DEFAULT_REVERSIBLE_WINDOW: Duration = Duration::Time(24 * 60 * 60);
+
+// 한 번의 실행에서 자동 확정을 시도하는 최대 건수
+const MAX_AUTO_SETTLE: usize = 10;
+// 한 번의 실행에서 확인하는 대기 전송 최대 건수 (만기 전 건 포함)
+const MAX_AUTO_SETTLE_SCAN: usize = 30;
+
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+pub fn execute_reversible_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    #[cfg(test)]
+    let rcpt_addr = Addr::unchecked(&recipient);
+
+    #[cfg(not(test))]
+    let rcpt_addr = deps.api.addr_validate(&recipient)?;
+
+    assert_not_frozen(deps.storage, &[&info.sender, &rcpt_addr])?;
+    if amount.is_zero() {
+        return Err(ContractError::InvalidAmount {});
+    }
+    assert_max_tx(deps.storage, &info.sender, amount)?;
+    // 확정 시에는 메모를 붙일 수 없으므로 메모 필수 주소는 미리 거부
+    assert_memo_attached(deps.storage, &rcpt_addr, None)?;
+
+    // 발신자 잔액에서 대기 상태로 이동 (recipient는 확정 전까지 사용 불가)
+    let hook_msgs = debit_escrow(deps.storage, &env.block, &info.sender, amount)?;
+
+    let window = REVERSIBLE_WINDOW
+        .may_load(deps.storage)?
+        .unwrap_or(DEFAULT_REVERSIBLE_WINDOW);
+    let settle_at = window.after(&env.block);
+
+    let id = PENDING_TRANSFER_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    PENDING_TRANSFER_COUNT.save(deps.storage, &id)?;
+    pending_transfers().save(
+        deps.storage,
+        id,
+        &PendingTransfer {
+            id,
+            sender: info.sender.clone(),
+            recipient: rcpt_addr,
+            amount,
+            settle_at,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "reversible_transfer")
+        .add_attribute("id", id.to_string())
+        .add_attribute("from", info.sender)
+        .add_attribute("to", recipient)
+        .add_attribute("amount", amount)
+        .add_attribute("settle_at", settle_at.to_string())
+        .add_submessages(hook_msgs))
+}
+
+pub fn execute_reverse_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let pending = pending_transfers().load(deps.storage, id)?;
+    if pending.sender != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    if pending.settle_at.is_expired(&env.block) {
+        return Err(ContractError::ReversalWindowClosed {});
+    }
+    assert_not_frozen(deps.storage, &[&pending.sender])?;
+    pending_transfers().remove(deps.storage, id)?;
+
+    // 전액 환불
+    let new_balance = BALANCES.update(deps.storage, &pending.sender, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default() + pending.amount)
+    })?;
+    let hook_msgs = balance_change_hooks(
+        deps.storage,
+        vec![(pending.sender.clone(), new_balance - pending.amount, new_balance)],
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "reverse_transfer")
+        .add_attribute("id", id.to_string())
+        .add_attribute("from", pending.sender)
+        .add_attribute("amount", pending.amount)
+        .add_submessages(hook_msgs))
+}
+
+pub fn execute_accept_transfer(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let pending = pending_transfers().load(deps.storage, id)?;
+    if pending.recipient != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !pending.settle_at.is_expired(&env.block) {
+        return Err(ContractError::ReversalWindowOpen {});
+    }
+
+    let (fee_result, hook_msgs) = settle(deps.branch(), &pending)?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "accept_transfer")
+        .add_attribute("id", id.to_string())
+        .add_attribute("from", pending.sender)
+        .add_attribute("to", pending.recipient)
+        .add_attribute("amount", fee_result.transfer_amount)
+        .add_submessages(hook_msgs);
+
+    if !fee_result.fee_msgs.is_empty() {
+        response = response.add_messages(fee_result.fee_msgs.clone());
+    }
+    let fee_config = FEE_CONFIG.may_load(deps.storage)?;
+    response = add_fee_attributes(response, &fee_result, fee_config.as_ref());
+
+    Ok(response)
+}
+
+// 대기 중인 전송을 recipient에게 확정. 수수료는 확정 시점에 적용.
+// 수수료 계산 결과와 잔액 변경 훅 메시지를 반환
+fn settle(
+    mut deps: DepsMut,
+    pending: &PendingTransfer,
+) -> Result<(FeeCalculationResult, Vec<SubMsg>), ContractError> {
+    assert_not_frozen(deps.storage, &[&pending.sender, &pending.recipient])?;
+
+    // 자동 확정은 실패한 건을 건너뛰므로 지급이 끝난 뒤에 삭제
+    let (fee_result, hook_msgs) = pay_from_escrow(
+        deps.branch(),
+        &pending.sender,
+        &pending.recipient,
+        pending.amount,
+    )?;
+    pending_transfers().remove(deps.storage, pending.id)?;
+
+    Ok((fee_result, hook_msgs))
+}
+
+/// 자동 확정을 실행하는 메시지. sender 본인 잔액을 내보내는 전송 계열만 해당
+pub fn triggers_auto_settle(msg: &ExecuteMsg) -> bool {
+    matches!(
+        msg,
+        ExecuteMsg::Transfer { .. }
+            | ExecuteMsg::TransferWithMemo { .. }
+            | ExecuteMsg::BatchTransfer { .. }
+            | ExecuteMsg::Send { .. }
+            | ExecuteMsg::SendWithMemo { .. }
+            | ExecuteMsg::ScheduleTransfer { .. }
+            | ExecuteMsg::LockHtlc { .. }
+            | ExecuteMsg::ReversibleTransfer { .. }
+            | ExecuteMsg::CreateStream { .. }
+    )
+}
+
+/// 전송 실행 전에 recipient 앞으로 되돌림 기간이 지난 전송을 자동 확정.
+/// 확인 건수는 MAX_AUTO_SETTLE_SCAN으로 제한하고, 지난번 위치(AUTO_SETTLE_CURSOR)부터
+/// 순환하며 확인한다. 확정할 수 없는 건(동결, 지갑 한도 등)은 건너뛰고 실행 자체는 막지 않는다
+pub fn settle_matured_transfers(
+    mut deps: DepsMut,
+    env: &Env,
+    recipient: &Addr,
+) -> Result<Response, ContractError> {
+    let mut response = Response::new();
+
+    // 전송 또는 수수료 지급이 정지된 동안에는 확정하지 않음
+    let operations = vec![PausableOperation::Transfer, PausableOperation::FeePayouts];
+    if assert_operations_allowed(deps.storage, operations).is_err() {
+        return Ok(response);
+    }
+
+    let cursor = AUTO_SETTLE_CURSOR.may_load(deps.storage, recipient)?;
+    let index = pending_transfers().idx.recipient.prefix(recipient.clone());
+    let mut scanned = index
+        .range(deps.storage, cursor.map(Bound::exclusive), None, Order::Ascending)
+        .take(MAX_AUTO_SETTLE_SCAN)
+        .collect::<StdResult<Vec<_>>>()?;
+    // 끝까지 확인했으면 처음부터 cursor까지 이어서 확인
+    if let Some(cursor) = cursor {
+        if scanned.len() < MAX_AUTO_SETTLE_SCAN {
+            let wrapped = index
+                .range(deps.storage, None, Some(Bound::inclusive(cursor)), Order::Ascending)
+                .take(MAX_AUTO_SETTLE_SCAN - scanned.len())
+                .collect::<StdResult<Vec<_>>>()?;
+            scanned.extend(wrapped);
+        }
+    }
+    if scanned.is_empty() {
+        AUTO_SETTLE_CURSOR.remove(deps.storage, recipient);
+        return Ok(response);
+    }
+
+    let mut attempts = 0;
+    let mut last_checked = None;
+    for (id, pending) in scanned {
+        if attempts == MAX_AUTO_SETTLE {
+            break;
+        }
+        last_checked = Some(id);
+        if !pending.settle_at.is_expired(&env.block) {
+            continue;
+        }
+        attempts += 1;
+        let Ok((fee_result, hook_msgs)) = settle(deps.branch(), &pending) else {
+            continue;
+        };
+
+        let event = Event::new("settle_transfer")
+            .add_attribute("id", id.to_string())
+            .add_attribute("from", pending.sender)
+            .add_attribute("to", pending.recipient)
+            .add_attribute("amount", fee_result.transfer_amount)
+            .add_attribute("fee_amount", fee_result.fee_amount);
+        response = response
+            .add_event(event)
+            .add_messages(fee_result.fee_msgs)
+            .add_submessages(hook_msgs);
+    }
+    if let Some(id) = last_checked {
+        AUTO_SETTLE_CURSOR.save(deps.storage, recipient, &id)?;
+    }
+    Ok(response)
+}
+
+pub fn execute_set_reversible_window(
+    deps: DepsMut,
+    info: MessageInfo,
+    window: Duration,
+) -> Result<Response, ContractError> {
+    let extended_info = EXTENDED_INFO.load(deps.storage)?;
+    if info.sender != extended_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    REVERSIBLE_WINDOW.save(deps.storage, &window)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_reversible_window")
+        .add_attribute("window", window.to_string()))
+}
+
+fn to_response(pending: PendingTransfer) -> PendingTransferResponse {
+    PendingTransferResponse {
+        id: pending.id,
+        sender: pending.sender.into(),
+        recipient: pending.recipient.into(),
+        amount: pending.amount,
+        settle_at: pending.settle_at,
+    }
+}
+
+pub fn query_pending_transfer(deps: Deps, id: u64) -> StdResult<PendingTransferResponse> {
+    Ok(to_response(pending_transfers().load(deps.storage, id)?))
+}
+
+pub fn query_pending_transfers(
+    deps: Deps,
+    recipient: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<PendingTransfersResponse> {
+    let recipient_addr = deps.api.addr_validate(&recipient)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let transfers = pending_transfers()
+        .idx
+        .recipient
+        .prefix(recipient_addr)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, pending)| to_response(pending)))
+        .collect::<StdResult<_>>()?;
+    Ok(PendingTransfersResponse { transfers })
+}
@@ -0,0 +1,155 @@
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128};
+use cw20::Expiration;
+
+#[cfg(test)]
+use cosmwasm_std::Addr;
+
+use crate::error::ContractError;
+use crate::escrow::{debit_escrow, pay_from_escrow};
+use crate::fee::add_fee_attributes;
+use crate::freeze::assert_not_frozen;
+use crate::hooks::balance_change_hooks;
+use crate::limits::assert_max_tx;
+use crate::memo::assert_memo_attached;
+use crate::msg::ScheduledTransferResponse;
+use crate::state::{
+    ScheduledTransfer, BALANCES, FEE_CONFIG, SCHEDULED_TRANSFERS, SCHEDULED_TRANSFER_COUNT,
+};
+
+
+pub fn execute_schedule_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+    release_at: Expiration,
+    cancellable_until: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    #[cfg(test)]
+    let rcpt_addr = Addr::unchecked(&recipient);
+
+    #[cfg(not(test))]
+    let rcpt_addr = deps.api.addr_validate(&recipient)?;
+
+    assert_not_frozen(deps.storage, &[&info.sender, &rcpt_addr])?;
+    if amount.is_zero() {
+        return Err(ContractError::InvalidAmount {});
+    }
+    if release_at.is_expired(&env.block) {
+        return Err(ContractError::InvalidExpiration {});
+    }
+    assert_max_tx(deps.storage, &info.sender, amount)?;
+    // 지급 시에는 메모를 붙일 수 없으므로 메모 필수 주소는 미리 거부
+    assert_memo_attached(deps.storage, &rcpt_addr, None)?;
+
+    let hook_msgs = debit_escrow(deps.storage, &env.block, &info.sender, amount)?;
+
+    let id = SCHEDULED_TRANSFER_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    SCHEDULED_TRANSFER_COUNT.save(deps.storage, &id)?;
+    SCHEDULED_TRANSFERS.save(
+        deps.storage,
+        id,
+        &ScheduledTransfer {
+            id,
+            sender: info.sender.clone(),
+            recipient: rcpt_addr,
+            amount,
+            release_at,
+            cancellable_until,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "schedule_transfer")
+        .add_attribute("id", id.to_string())
+        .add_attribute("from", info.sender)
+        .add_attribute("to", recipient)
+        .add_attribute("amount", amount)
+        .add_attribute("release_at", release_at.to_string())
+        .add_submessages(hook_msgs))
+}
+
+// release_at이 지나면 누구나 지급 실행 가능. 수수료는 지급 시점에 적용
+pub fn execute_release_scheduled(
+    mut deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let scheduled = SCHEDULED_TRANSFERS.load(deps.storage, id)?;
+    if !scheduled.release_at.is_expired(&env.block) {
+        return Err(ContractError::NotYetReleasable {});
+    }
+    assert_not_frozen(deps.storage, &[&scheduled.sender, &scheduled.recipient])?;
+    SCHEDULED_TRANSFERS.remove(deps.storage, id);
+
+    let (fee_result, hook_msgs) = pay_from_escrow(
+        deps.branch(),
+        &scheduled.sender,
+        &scheduled.recipient,
+        scheduled.amount,
+    )?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "release_scheduled")
+        .add_attribute("id", id.to_string())
+        .add_attribute("from", scheduled.sender)
+        .add_attribute("to", scheduled.recipient)
+        .add_attribute("amount", fee_result.transfer_amount)
+        .add_submessages(hook_msgs);
+
+    if !fee_result.fee_msgs.is_empty() {
+        response = response.add_messages(fee_result.fee_msgs.clone());
+    }
+    let fee_config = FEE_CONFIG.may_load(deps.storage)?;
+    response = add_fee_attributes(response, &fee_result, fee_config.as_ref());
+
+    Ok(response)
+}
+
+pub fn execute_cancel_scheduled(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let scheduled = SCHEDULED_TRANSFERS.load(deps.storage, id)?;
+    if scheduled.sender != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    match scheduled.cancellable_until {
+        Some(until) if !until.is_expired(&env.block) => {}
+        _ => return Err(ContractError::NotCancellable {}),
+    }
+    assert_not_frozen(deps.storage, &[&scheduled.sender])?;
+    SCHEDULED_TRANSFERS.remove(deps.storage, id);
+
+    // 에스크로 금액 전액 환불
+    let new_balance = BALANCES.update(deps.storage, &scheduled.sender, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default() + scheduled.amount)
+    })?;
+    let hook_msgs = balance_change_hooks(
+        deps.storage,
+        vec![(scheduled.sender.clone(), new_balance - scheduled.amount, new_balance)],
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_scheduled")
+        .add_attribute("id", id.to_string())
+        .add_attribute("from", scheduled.sender)
+        .add_attribute("amount", scheduled.amount)
+        .add_submessages(hook_msgs))
+}
+
+pub fn query_scheduled_transfer(deps: Deps, id: u64) -> StdResult<ScheduledTransferResponse> {
+    let scheduled = SCHEDULED_TRANSFERS.load(deps.storage, id)?;
+    Ok(ScheduledTransferResponse {
+        id: scheduled.id,
+        sender: scheduled.sender.into(),
+        recipient: scheduled.recipient.into(),
+        amount: scheduled.amount,
+        release_at: scheduled.release_at,
+        cancellable_until: scheduled.cancellable_until,
+    })
+}
@@ -1,10 +1,13 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal, Uint128};
-use cw_storage_plus::{Item, Map};
-use cw20::{AllowanceResponse, Logo, MarketingInfoResponse};
+use cosmwasm_std::{Addr, Decimal, HexBinary, Timestamp, Uint128};
+use cw_controllers::Hooks;
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex, SnapshotItem, Strategy};
+use cw20::{AllowanceResponse, Expiration, Logo, MarketingInfoResponse};
+use cw_utils::Duration;
 
-use crate::fee::{FeeTokenType, FeeType};
-use crate::msg::ConfigInfo;
+use crate::fee::{BatchFeeMode, FeeTokenType, FeeType};
+use crate::msg::{ConfigInfo, VestingSchedule};
+use crate::pause::PausableOperation;
 
 #[cw_serde]
 pub struct TokenInfo {
@@ -35,6 +38,61 @@ impl TokenInfo {
     }
 }
 
+// 기본 minter 교체 제안. new_minter가 만료 전에 수락해야 교체된다
+#[cw_serde]
+pub struct PendingMinter {
+    pub new_minter: Addr,
+    pub expires: Expiration,
+}
+
+// 추가 minter별 발행 할당량. minted는 지금까지 발행한 누적량
+#[cw_serde]
+pub struct MinterQuota {
+    pub allowance: Uint128,
+    pub minted: Uint128,
+    pub expires: Expiration,
+}
+
+impl MinterQuota {
+    pub fn remaining(&self) -> Uint128 {
+        self.allowance.saturating_sub(self.minted)
+    }
+}
+
+// 에포크(블록 수 또는 초) 단위 최대 발행량
+#[cw_serde]
+pub struct MintRateLimit {
+    pub max_per_epoch: Uint128,
+    pub epoch: Duration,
+}
+
+// 에포크 번호와 해당 에포크에서 발행한 양
+#[cw_serde]
+pub struct MintEpochUsage {
+    pub epoch: u64,
+    pub minted: Uint128,
+}
+
+// 블록당 발행량과 반감 주기, 수령 비율로 정의되는 생태계 발행 스케줄
+#[cw_serde]
+pub struct EmissionSchedule {
+    pub rate_per_block: Uint128,
+    pub start_height: u64,
+    /// 지정 시 start_height부터 이 블록 수마다 블록당 발행량이 절반으로 줄어든다
+    pub halving_interval: Option<u64>,
+    /// 수령 주소와 비율 (합계 1)
+    pub recipients: Vec<(Addr, Decimal)>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct EmissionState {
+    pub last_release_height: u64,
+    /// 스케줄 변경 전에 쌓였지만 아직 발행되지 않은 양 (다음 지급 시 함께 발행)
+    pub carried: Uint128,
+    pub total_released: Uint128,
+}
+
 // 수수료 수취인 정보
 #[cw_serde]
 pub struct FeeCollectorInfo {
@@ -49,19 +107,268 @@ pub struct FeeConfig {
     pub token_type: FeeTokenType,
     pub collectors: Vec<FeeCollectorInfo>,
     pub is_active: bool,
+    #[serde(default)]
+    pub batch_fee_mode: BatchFeeMode,
+}
+
+// 지정 시점 이후 수신자에게 지급되는 예약 전송 (에스크로)
+#[cw_serde]
+pub struct ScheduledTransfer {
+    pub id: u64,
+    pub sender: Addr,
+    pub recipient: Addr,
+    pub amount: Uint128,
+    pub release_at: Expiration,
+    pub cancellable_until: Option<Expiration>,
+}
+
+// 출시 초기 고래 방지 한도. None이면 제한 없음
+#[cw_serde]
+#[derive(Default)]
+pub struct TransferLimits {
+    pub max_tx_amount: Option<Uint128>,
+    pub max_wallet_balance: Option<Uint128>,
+    pub exempt: Vec<Addr>,
+}
+
+// 주소별 마지막 출금 시점
+#[cw_serde]
+pub struct LastTransfer {
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+// 베스팅 지급 한 건
+#[cw_serde]
+pub struct VestingGrant {
+    pub total: Uint128,
+    pub schedule: VestingSchedule,
+}
+
+// 계정별 베스팅. 잠긴 물량은 잔액에 포함되지만 출금할 수 없다.
+// 여러 건을 지급받을 수 있고 claimed는 전체 합산 기준
+#[cw_serde]
+pub struct VestingAccount {
+    pub grants: Vec<VestingGrant>,
+    pub claimed: Uint128,
+}
+
+// 해시 시간 잠금 전송 (HTLC). timelock 전에는 preimage로 청구, 이후에는 sender에게 환불
+#[cw_serde]
+pub struct Htlc {
+    pub id: u64,
+    pub sender: Addr,
+    pub recipient: Addr,
+    pub amount: Uint128,
+    /// preimage의 sha256 해시 (32 bytes)
+    pub hashlock: HexBinary,
+    pub timelock: Expiration,
+}
+
+pub struct HtlcIndexes<'a> {
+    pub sender: MultiIndex<'a, Addr, Htlc, u64>,
+    pub recipient: MultiIndex<'a, Addr, Htlc, u64>,
+}
+
+impl IndexList<Htlc> for HtlcIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Htlc>> + '_> {
+        let v: Vec<&dyn Index<Htlc>> = vec![&self.sender, &self.recipient];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn htlcs<'a>() -> IndexedMap<u64, Htlc, HtlcIndexes<'a>> {
+    let indexes = HtlcIndexes {
+        sender: MultiIndex::new(|_pk, htlc| htlc.sender.clone(), "htlcs", "htlcs__sender"),
+        recipient: MultiIndex::new(
+            |_pk, htlc| htlc.recipient.clone(),
+            "htlcs",
+            "htlcs__recipient",
+        ),
+    };
+    IndexedMap::new("htlcs", indexes)
+}
+
+// 되돌릴 수 있는 전송. settle_at 전까지 sender가 되돌릴 수 있고 이후 recipient에게 확정된다
+#[cw_serde]
+pub struct PendingTransfer {
+    pub id: u64,
+    pub sender: Addr,
+    pub recipient: Addr,
+    pub amount: Uint128,
+    pub settle_at: Expiration,
+}
+
+pub struct PendingTransferIndexes<'a> {
+    pub recipient: MultiIndex<'a, Addr, PendingTransfer, u64>,
+}
+
+impl IndexList<PendingTransfer> for PendingTransferIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<PendingTransfer>> + '_> {
+        let v: Vec<&dyn Index<PendingTransfer>> = vec![&self.recipient];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn pending_transfers<'a>() -> IndexedMap<u64, PendingTransfer, PendingTransferIndexes<'a>> {
+    let indexes = PendingTransferIndexes {
+        recipient: MultiIndex::new(
+            |_pk, pending| pending.recipient.clone(),
+            "pending_transfers",
+            "pending_transfers__recipient",
+        ),
+    };
+    IndexedMap::new("pending_transfers", indexes)
+}
+
+// 블록 단위 선형 지급 스트림. start_height부터 end_height까지 total이 균등하게 풀린다
+#[cw_serde]
+pub struct Stream {
+    pub id: u64,
+    pub sender: Addr,
+    pub recipient: Addr,
+    pub total: Uint128,
+    /// recipient가 이미 인출한 양 (수수료 차감 전 기준)
+    pub withdrawn: Uint128,
+    pub start_height: u64,
+    pub end_height: u64,
+}
+
+pub struct StreamIndexes<'a> {
+    pub sender: MultiIndex<'a, Addr, Stream, u64>,
+    pub recipient: MultiIndex<'a, Addr, Stream, u64>,
+}
+
+impl IndexList<Stream> for StreamIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Stream>> + '_> {
+        let v: Vec<&dyn Index<Stream>> = vec![&self.sender, &self.recipient];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn streams<'a>() -> IndexedMap<u64, Stream, StreamIndexes<'a>> {
+    let indexes = StreamIndexes {
+        sender: MultiIndex::new(|_pk, stream| stream.sender.clone(), "streams", "streams__sender"),
+        recipient: MultiIndex::new(
+            |_pk, stream| stream.recipient.clone(),
+            "streams",
+            "streams__recipient",
+        ),
+    };
+    IndexedMap::new("streams", indexes)
+}
+
+// 소각 영수증. 게임 서버나 브리지가 이벤트 인덱서 없이 소각을 검증할 수 있도록 보관
+#[cw_serde]
+pub struct BurnReceipt {
+    pub id: u64,
+    pub burner: Addr,
+    pub amount: Uint128,
+    pub reason: String,
+    pub reference: Option<String>,
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+pub struct BurnReceiptIndexes<'a> {
+    pub burner: MultiIndex<'a, Addr, BurnReceipt, u64>,
+}
+
+impl IndexList<BurnReceipt> for BurnReceiptIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<BurnReceipt>> + '_> {
+        let v: Vec<&dyn Index<BurnReceipt>> = vec![&self.burner];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn burn_receipts<'a>() -> IndexedMap<u64, BurnReceipt, BurnReceiptIndexes<'a>> {
+    let indexes = BurnReceiptIndexes {
+        burner: MultiIndex::new(
+            |_pk, receipt| receipt.burner.clone(),
+            "burn_receipts",
+            "burn_receipts__burner",
+        ),
+    };
+    IndexedMap::new("burn_receipts", indexes)
 }
 
 // 기본 CW20 상태 저장
 pub const TOKEN_INFO: Item<TokenInfo> = Item::new("token_info");
+// 블록 높이별 총 발행량 기록. TOKEN_INFO.total_supply가 바뀔 때마다 함께 저장
+pub const TOTAL_SUPPLY_HISTORY: SnapshotItem<Uint128> = SnapshotItem::new(
+    "total_supply_history",
+    "total_supply_history__checkpoints",
+    "total_supply_history__changelog",
+    Strategy::EveryBlock,
+);
 pub const MARKETING_INFO: Item<MarketingInfoResponse> = Item::new("marketing_info");
 pub const LOGO: Item<Logo> = Item::new("logo");
 pub const BALANCES: Map<&Addr, Uint128> = Map::new("balance");
 pub const ALLOWANCES: Map<(&Addr, &Addr), AllowanceResponse> = Map::new("allowance");
 pub const ALLOWANCES_SPENDER: Map<(&Addr, &Addr), AllowanceResponse> = Map::new("allowance_spender");
+// (owner, spender) 허용량으로 전송 가능한 수신자 목록. 항목이 없으면 제한 없음
+pub const ALLOWED_RECIPIENTS: Map<(&Addr, &Addr), Vec<Addr>> = Map::new("allowed_recipients");
+// (owner, operator) 운영자 승인과 만료 시점. 운영자는 허용량 없이 owner의 토큰을 이동할 수 있다
+pub const OPERATORS: Map<(&Addr, &Addr), Expiration> = Map::new("operators");
+
+// 관리자가 등록한 추가 minter 목록. 발행 총량은 TokenInfo의 cap이 계속 상한으로 적용된다
+pub const MINTERS: Map<&Addr, MinterQuota> = Map::new("minters");
+pub const PENDING_MINTER: Item<PendingMinter> = Item::new("pending_minter");
+
+// 발행 속도 제한. 전체 한도와 minter별 한도가 모두 적용됨
+pub const GLOBAL_MINT_RATE_LIMIT: Item<MintRateLimit> = Item::new("global_mint_rate_limit");
+pub const GLOBAL_MINT_USAGE: Item<MintEpochUsage> = Item::new("global_mint_usage");
+pub const MINTER_RATE_LIMITS: Map<&Addr, MintRateLimit> = Map::new("minter_rate_limits");
+pub const MINTER_MINT_USAGE: Map<&Addr, MintEpochUsage> = Map::new("minter_mint_usage");
+
+// 생태계 발행 스케줄과 지급 현황
+pub const EMISSION_SCHEDULE: Item<EmissionSchedule> = Item::new("emission_schedule");
+pub const EMISSION_STATE: Item<EmissionState> = Item::new("emission_state");
 
 // 확장 기능을 위한 추가 상태
 pub const EXTENDED_INFO: Item<ExtendedTokenInfo> = Item::new("extended_info");
 pub const CONFIG: Item<ConfigInfo> = Item::new("config");
 
+// 예약 전송 에스크로 장부
+pub const SCHEDULED_TRANSFERS: Map<u64, ScheduledTransfer> = Map::new("scheduled_transfers");
+pub const SCHEDULED_TRANSFER_COUNT: Item<u64> = Item::new("scheduled_transfer_count");
+
+// 계정별 베스팅 지급 목록
+pub const VESTING: Map<&Addr, VestingAccount> = Map::new("vesting");
+
+// 관리자가 동결한 주소와 사유. 동결된 주소는 송금, 수신, 대리 전송 모두 불가
+pub const FROZEN_ACCOUNTS: Map<&Addr, String> = Map::new("frozen_accounts");
+
+pub const TRANSFER_LIMITS: Item<TransferLimits> = Item::new("transfer_limits");
+// 같은 주소의 연속 출금 사이 최소 간격 (블록 또는 초). 전송 한도와 별개로 설정하며 면제 주소도 적용
+pub const TRANSFER_COOLDOWN: Item<Duration> = Item::new("transfer_cooldown");
+pub const LAST_TRANSFER: Map<&Addr, LastTransfer> = Map::new("last_transfer");
+
+// 일시 정지된 작업 목록 (비어 있으면 정상 운영)
+pub const PAUSED_OPERATIONS: Item<Vec<PausableOperation>> = Item::new("paused_operations");
+
+// HTLC 에스크로 (조회용 sender/recipient 인덱스 포함)
+pub const HTLC_COUNT: Item<u64> = Item::new("htlc_count");
+
+// 되돌릴 수 있는 전송 (recipient 인덱스로 자동 확정 및 조회)
+pub const PENDING_TRANSFER_COUNT: Item<u64> = Item::new("pending_transfer_count");
+// recipient별 자동 확정 위치. 다음 실행은 이 ID 다음부터 확인 (확정 실패 건에 막히지 않도록)
+pub const AUTO_SETTLE_CURSOR: Map<&Addr, u64> = Map::new("auto_settle_cursor");
+// 되돌릴 수 있는 기간 (미설정 시 기본값)
+pub const REVERSIBLE_WINDOW: Item<Duration> = Item::new("reversible_window");
+
+// 지급 스트림 (조회용 sender/recipient 인덱스 포함)
+pub const STREAM_COUNT: Item<u64> = Item::new("stream_count");
+
+// 소각 영수증 ID 카운터
+pub const BURN_RECEIPT_COUNT: Item<u64> = Item::new("burn_receipt_count");
+
+// 잔액 변경 훅 컨트랙트 목록과 훅별 실패 시 되돌림 여부
+pub const BALANCE_HOOKS: Hooks = Hooks::new("balance_hooks");
+pub const HOOK_REVERT_ON_ERROR: Map<&Addr, bool> = Map::new("hook_revert_on_error");
+
+// 메모 없이는 입금을 받지 않는 주소 (거래소, 수탁 지갑 등)
+pub const MEMO_REQUIRED: Map<&Addr, bool> = Map::new("memo_required");
+
 // 수수료 관련 상태
 pub const FEE_CONFIG: Item<FeeConfig> = Item::new("fee_config");
\ No newline at end of file
@@ -0,0 +1,271 @@
+use cosmwasm_std::{
+    Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Uint128,
+};
+use cw_storage_plus::Bound;
+
+#[cfg(test)]
+use cosmwasm_std::Addr;
+
+use crate::error::ContractError;
+use crate::escrow::{debit_escrow, pay_from_escrow};
+use crate::fee::add_fee_attributes;
+use crate::freeze::assert_not_frozen;
+use crate::hooks::balance_change_hooks;
+use crate::limits::assert_max_tx;
+use crate::memo::assert_memo_attached;
+use crate::msg::{StreamResponse, StreamsResponse};
+use crate::state::{streams, Stream, BALANCES, FEE_CONFIG, STREAM_COUNT};
+
+
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+/// height 시점까지 쌓인 양. start 이전 0, end 이후 전액
+pub fn streamed_amount(stream: &Stream, height: u64) -> Uint128 {
+    if height <= stream.start_height {
+        Uint128::zero()
+    } else if height >= stream.end_height {
+        stream.total
+    } else {
+        let elapsed = height - stream.start_height;
+        let duration = stream.end_height - stream.start_height;
+        stream.total.multiply_ratio(elapsed, duration)
+    }
+}
+
+pub fn withdrawable_amount(stream: &Stream, height: u64) -> Uint128 {
+    streamed_amount(stream, height) - stream.withdrawn
+}
+
+pub fn execute_create_stream(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    total: Uint128,
+    start: u64,
+    end: u64,
+) -> Result<Response, ContractError> {
+    #[cfg(test)]
+    let rcpt_addr = Addr::unchecked(&recipient);
+
+    #[cfg(not(test))]
+    let rcpt_addr = deps.api.addr_validate(&recipient)?;
+
+    assert_not_frozen(deps.storage, &[&info.sender, &rcpt_addr])?;
+    if total.is_zero() {
+        return Err(ContractError::InvalidAmount {});
+    }
+    if rcpt_addr == info.sender {
+        return Err(ContractError::CannotSetOwnAccount {});
+    }
+    if start < env.block.height {
+        return Err(ContractError::InvalidStream {
+            msg: "start must not be in the past".to_string(),
+        });
+    }
+    if end <= start {
+        return Err(ContractError::InvalidStream {
+            msg: "end must be after start".to_string(),
+        });
+    }
+    assert_max_tx(deps.storage, &info.sender, total)?;
+    // 인출 시에는 메모를 붙일 수 없으므로 메모 필수 주소는 미리 거부
+    assert_memo_attached(deps.storage, &rcpt_addr, None)?;
+
+    let hook_msgs = debit_escrow(deps.storage, &env.block, &info.sender, total)?;
+
+    let id = STREAM_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    STREAM_COUNT.save(deps.storage, &id)?;
+    streams().save(
+        deps.storage,
+        id,
+        &Stream {
+            id,
+            sender: info.sender.clone(),
+            recipient: rcpt_addr,
+            total,
+            withdrawn: Uint128::zero(),
+            start_height: start,
+            end_height: end,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "create_stream")
+        .add_attribute("id", id.to_string())
+        .add_attribute("from", info.sender)
+        .add_attribute("to", recipient)
+        .add_attribute("total", total)
+        .add_attribute("start_height", start.to_string())
+        .add_attribute("end_height", end.to_string())
+        .add_submessages(hook_msgs))
+}
+
+pub fn execute_withdraw_stream(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let mut stream = streams().load(deps.storage, id)?;
+    if stream.recipient != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    assert_not_frozen(deps.storage, &[&stream.sender, &stream.recipient])?;
+
+    let amount = withdrawable_amount(&stream, env.block.height);
+    if amount.is_zero() {
+        return Err(ContractError::NothingToWithdraw {});
+    }
+    let (fee_result, hook_msgs) = pay_from_escrow(
+        deps.branch(),
+        &stream.sender,
+        &stream.recipient,
+        amount,
+    )?;
+
+    // 전액 인출되면 스트림 종료
+    stream.withdrawn += amount;
+    if stream.withdrawn == stream.total {
+        streams().remove(deps.storage, id)?;
+    } else {
+        streams().save(deps.storage, id, &stream)?;
+    }
+
+    let mut response = Response::new()
+        .add_attribute("action", "withdraw_stream")
+        .add_attribute("id", id.to_string())
+        .add_attribute("from", stream.sender)
+        .add_attribute("to", stream.recipient)
+        .add_attribute("amount", fee_result.transfer_amount)
+        .add_attribute("remaining", stream.total - stream.withdrawn)
+        .add_submessages(hook_msgs);
+
+    if !fee_result.fee_msgs.is_empty() {
+        response = response.add_messages(fee_result.fee_msgs.clone());
+    }
+    let fee_config = FEE_CONFIG.may_load(deps.storage)?;
+    response = add_fee_attributes(response, &fee_result, fee_config.as_ref());
+
+    Ok(response)
+}
+
+/// sender가 스트림 취소. 이미 쌓인 양은 recipient에게 지급(수수료 적용), 나머지는 sender에게 반환
+pub fn execute_cancel_stream(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let stream = streams().load(deps.storage, id)?;
+    if stream.sender != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    assert_not_frozen(deps.storage, &[&stream.sender, &stream.recipient])?;
+
+    let streamed = streamed_amount(&stream, env.block.height);
+    let owed = streamed - stream.withdrawn;
+    let refund = stream.total - streamed;
+
+    let (fee_result, mut hook_msgs) = if owed.is_zero() {
+        (None, vec![])
+    } else {
+        let (fee_result, hook_msgs) = pay_from_escrow(
+            deps.branch(),
+            &stream.sender,
+            &stream.recipient,
+            owed,
+        )?;
+        (Some(fee_result), hook_msgs)
+    };
+    streams().remove(deps.storage, id)?;
+    let sender_balance = BALANCES.update(deps.storage, &stream.sender, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default() + refund)
+    })?;
+    hook_msgs.extend(balance_change_hooks(
+        deps.storage,
+        vec![(stream.sender.clone(), sender_balance - refund, sender_balance)],
+    )?);
+
+    let mut response = Response::new()
+        .add_attribute("action", "cancel_stream")
+        .add_attribute("id", id.to_string())
+        .add_attribute("from", stream.sender)
+        .add_attribute("to", stream.recipient)
+        .add_attribute("refund", refund)
+        .add_submessages(hook_msgs);
+
+    if let Some(fee_result) = fee_result {
+        response = response.add_attribute("amount", fee_result.transfer_amount);
+        if !fee_result.fee_msgs.is_empty() {
+            response = response.add_messages(fee_result.fee_msgs.clone());
+        }
+        let fee_config = FEE_CONFIG.may_load(deps.storage)?;
+        response = add_fee_attributes(response, &fee_result, fee_config.as_ref());
+    }
+
+    Ok(response)
+}
+
+fn to_response(stream: Stream, height: u64) -> StreamResponse {
+    StreamResponse {
+        id: stream.id,
+        withdrawable: withdrawable_amount(&stream, height),
+        sender: stream.sender.into(),
+        recipient: stream.recipient.into(),
+        total: stream.total,
+        withdrawn: stream.withdrawn,
+        start_height: stream.start_height,
+        end_height: stream.end_height,
+    }
+}
+
+pub fn query_stream(deps: Deps, env: Env, id: u64) -> StdResult<StreamResponse> {
+    Ok(to_response(streams().load(deps.storage, id)?, env.block.height))
+}
+
+pub fn query_streams_by_sender(
+    deps: Deps,
+    env: Env,
+    sender: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<StreamsResponse> {
+    let sender_addr = deps.api.addr_validate(&sender)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let streams = streams()
+        .idx
+        .sender
+        .prefix(sender_addr)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, stream)| to_response(stream, env.block.height)))
+        .collect::<StdResult<_>>()?;
+    Ok(StreamsResponse { streams })
+}
+
+pub fn query_streams_by_recipient(
+    deps: Deps,
+    env: Env,
+    recipient: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<StreamsResponse> {
+    let recipient_addr = deps.api.addr_validate(&recipient)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let streams = streams()
+        .idx
+        .recipient
+        .prefix(recipient_addr)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, stream)| to_response(stream, env.block.height)))
+        .collect::<StdResult<_>>()?;
+    Ok(StreamsResponse { streams })
+}
@@ -0,0 +1,135 @@
+use cosmwasm_std::{Deps, DepsMut, MessageInfo, Response, StdResult, Storage, Uint128};
+
+use crate::error::ContractError;
+use crate::msg::{SupplyCapResponse, TotalSupplyAtHeightResponse};
+use crate::state::{CONFIG, EXTENDED_INFO, TOKEN_INFO, TOTAL_SUPPLY_HISTORY};
+
+/// 총 발행량 변경 기록. 발행/소각 경로에서 TOKEN_INFO 저장과 함께 호출
+pub fn record_total_supply(
+    storage: &mut dyn Storage,
+    height: u64,
+    total_supply: Uint128,
+) -> StdResult<()> {
+    TOTAL_SUPPLY_HISTORY.save(storage, &total_supply, height)
+}
+
+/// 기록이 없는 기존 컨트랙트는 마이그레이션 시점의 총 발행량부터 기록 시작
+pub fn seed_total_supply_history(storage: &mut dyn Storage, height: u64) -> StdResult<bool> {
+    if TOTAL_SUPPLY_HISTORY.may_load(storage)?.is_some() {
+        return Ok(false);
+    }
+    let total_supply = TOKEN_INFO.load(storage)?.total_supply;
+    record_total_supply(storage, height, total_supply)?;
+    Ok(true)
+}
+
+/// 모든 발행 경로에 적용되는 최대 발행량 (CONFIG.max_supply). 설정된 뒤에는 낮출 수만 있다
+pub fn max_supply(storage: &dyn Storage) -> StdResult<Option<Uint128>> {
+    Ok(CONFIG.load(storage)?.max_supply)
+}
+
+pub fn assert_within_max_supply(
+    storage: &dyn Storage,
+    total_supply: Uint128,
+) -> Result<(), ContractError> {
+    if let Some(max_supply) = max_supply(storage)? {
+        if total_supply > max_supply {
+            return Err(ContractError::CannotExceedCap {});
+        }
+    }
+    Ok(())
+}
+
+/// 최대 발행량 변경 검증. 올리거나 해제할 수 없고 현재 발행량보다 낮출 수 없다
+pub fn validate_max_supply_change(
+    current: Option<Uint128>,
+    new: Option<Uint128>,
+    total_supply: Uint128,
+) -> Result<(), ContractError> {
+    match (current, new) {
+        (Some(_), None) => return Err(ContractError::CapCannotBeRaised {}),
+        (Some(current), Some(new)) if new > current => {
+            return Err(ContractError::CapCannotBeRaised {})
+        }
+        _ => {}
+    }
+    if new.is_some_and(|new| total_supply > new) {
+        return Err(ContractError::InvalidAmount {});
+    }
+    Ok(())
+}
+
+/// 최대 발행량 저장. minter 정보의 cap은 같은 값으로 맞춘다
+pub fn set_max_supply(storage: &mut dyn Storage, max_supply: Option<Uint128>) -> StdResult<()> {
+    let mut config = CONFIG.load(storage)?;
+    config.max_supply = max_supply;
+    if let Some(minter) = config.minter.as_mut() {
+        minter.cap = max_supply;
+    }
+    CONFIG.save(storage, &config)?;
+
+    let mut token_info = TOKEN_INFO.load(storage)?;
+    if let Some(mint) = token_info.mint.as_mut() {
+        mint.cap = max_supply;
+    }
+    TOKEN_INFO.save(storage, &token_info)?;
+    Ok(())
+}
+
+/// 마이그레이션 시 CONFIG.max_supply와 MinterData.cap 중 더 엄격한 값으로 통일.
+/// 이미 현재 발행량이 그 값을 넘었다면 현재 발행량으로 고정
+pub fn reconcile_max_supply(storage: &mut dyn Storage) -> StdResult<Option<Uint128>> {
+    let config = CONFIG.load(storage)?;
+    let token_info = TOKEN_INFO.load(storage)?;
+    let max_supply = [config.max_supply, token_info.get_cap()]
+        .into_iter()
+        .flatten()
+        .min()
+        .map(|cap| cap.max(token_info.total_supply));
+    set_max_supply(storage, max_supply)?;
+    Ok(max_supply)
+}
+
+/// 관리자 전용. UpdateConfig가 막혀 있어도 최대 발행량은 낮출 수 있다
+pub fn execute_lower_max_supply(
+    deps: DepsMut,
+    info: MessageInfo,
+    max_supply: Uint128,
+) -> Result<Response, ContractError> {
+    let extended_info = EXTENDED_INFO.load(deps.storage)?;
+    if info.sender != extended_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let token_info = TOKEN_INFO.load(deps.storage)?;
+    let current = self::max_supply(deps.storage)?;
+    validate_max_supply_change(current, Some(max_supply), token_info.total_supply)?;
+    set_max_supply(deps.storage, Some(max_supply))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "lower_max_supply")
+        .add_attribute("max_supply", max_supply))
+}
+
+pub fn query_supply_cap(deps: Deps) -> StdResult<SupplyCapResponse> {
+    let max_supply = max_supply(deps.storage)?;
+    let total_supply = TOKEN_INFO.load(deps.storage)?.total_supply;
+    Ok(SupplyCapResponse {
+        max_supply,
+        total_supply,
+        remaining: max_supply.map(|max| max.saturating_sub(total_supply)),
+        locked: max_supply.is_some(),
+    })
+}
+
+/// height 블록 시작 시점의 총 발행량 (해당 블록 안의 변경은 반영되지 않음).
+/// 기록 시작 이전 높이는 None
+pub fn query_total_supply_at_height(
+    deps: Deps,
+    height: u64,
+) -> StdResult<TotalSupplyAtHeightResponse> {
+    Ok(TotalSupplyAtHeightResponse {
+        height,
+        total_supply: TOTAL_SUPPLY_HISTORY.may_load_at_height(deps.storage, height)?,
+    })
+}
@@ -0,0 +1,288 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{Addr, Decimal, MessageInfo, OwnedDeps, Uint128};
+use cw_utils::Duration;
+
+use crate::contract::{execute, instantiate, query_balance, query_token_info};
+use crate::emission::query_emission_info;
+use crate::error::ContractError;
+use crate::msg::{
+    EmissionRecipient, ExecuteMsg, InitialBalance, InstantiateMsg, MintRateLimitInfo,
+    MinterResponse, VestingSchedule,
+};
+
+const ADMIN: &str = "admin";
+const ECOSYSTEM: &str = "ecosystem";
+const TREASURY: &str = "treasury";
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies();
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(1000),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: Some(MinterResponse {
+            minter: ADMIN.to_string(),
+            cap: Some(Uint128::new(5000)),
+        }),
+        created_on_platform: None,
+    };
+    let info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+    deps
+}
+
+fn recipient(address: &str, share: u64) -> EmissionRecipient {
+    EmissionRecipient {
+        address: address.to_string(),
+        share: Decimal::percent(share),
+    }
+}
+
+fn balance(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, address: &str) -> Uint128 {
+    query_balance(deps.as_ref(), address.to_string()).unwrap().balance
+}
+
+#[test]
+fn test_emission_release() {
+    let mut deps = setup();
+    let mut env = mock_env();
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+    let anyone = MessageInfo {
+        sender: Addr::unchecked("anyone"),
+        funds: vec![],
+    };
+
+    // 비율 합계가 1이 아니면 거부
+    let msg = ExecuteMsg::SetEmissionSchedule {
+        rate_per_block: Uint128::new(100),
+        start_height: None,
+        halving_interval: Some(10),
+        recipients: vec![recipient(ECOSYSTEM, 70), recipient(TREASURY, 20)],
+    };
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidEmissionSchedule { .. }));
+
+    // 블록당 100, 10블록마다 반감, 70:30 분배
+    let msg = ExecuteMsg::SetEmissionSchedule {
+        rate_per_block: Uint128::new(100),
+        start_height: None,
+        halving_interval: Some(10),
+        recipients: vec![recipient(ECOSYSTEM, 70), recipient(TREASURY, 30)],
+    };
+    let err = execute(deps.as_mut(), env.clone(), anyone.clone(), msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        anyone.clone(),
+        ExecuteMsg::ReleaseEmissions {},
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NothingToRelease {});
+
+    // 15블록 후: 10 * 100 + 5 * 50
+    env.block.height += 15;
+    let info = query_emission_info(deps.as_ref(), env.clone()).unwrap();
+    assert_eq!(info.current_rate, Uint128::new(50));
+    assert_eq!(info.next_amount, Uint128::new(1250));
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        anyone.clone(),
+        ExecuteMsg::ReleaseEmissions {},
+    )
+    .unwrap();
+    assert_eq!(balance(&deps, ECOSYSTEM), Uint128::new(875));
+    assert_eq!(balance(&deps, TREASURY), Uint128::new(375));
+    let info = query_emission_info(deps.as_ref(), env.clone()).unwrap();
+    assert_eq!(info.next_amount, Uint128::zero());
+    assert_eq!(info.total_released, Uint128::new(1250));
+    assert_eq!(info.last_release_height, env.block.height);
+
+    // 스케줄 변경 전 쌓인 5 * 50은 이월되어 새 비율로 발행
+    env.block.height += 5;
+    let msg = ExecuteMsg::SetEmissionSchedule {
+        rate_per_block: Uint128::new(1000),
+        start_height: None,
+        halving_interval: None,
+        recipients: vec![recipient(TREASURY, 100)],
+    };
+    execute(deps.as_mut(), env.clone(), admin_info, msg).unwrap();
+    env.block.height += 2;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        anyone.clone(),
+        ExecuteMsg::ReleaseEmissions {},
+    )
+    .unwrap();
+    assert_eq!(balance(&deps, TREASURY), Uint128::new(375 + 2250));
+
+    // cap까지만 발행하고 초과분은 소멸
+    env.block.height += 10;
+    let info = query_emission_info(deps.as_ref(), env.clone()).unwrap();
+    assert_eq!(info.next_amount, Uint128::new(500));
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        anyone.clone(),
+        ExecuteMsg::ReleaseEmissions {},
+    )
+    .unwrap();
+    assert_eq!(
+        query_token_info(deps.as_ref()).unwrap().total_supply,
+        Uint128::new(5000)
+    );
+    env.block.height += 1;
+    let err = execute(deps.as_mut(), env, anyone, ExecuteMsg::ReleaseEmissions {}).unwrap_err();
+    assert_eq!(err, ContractError::NothingToRelease {});
+}
+
+#[test]
+fn test_emission_respects_mint_rate_limit() {
+    let mut deps = setup();
+    let mut env = mock_env();
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+
+    // 100블록 에포크마다 전체 300까지 발행 가능
+    env.block.height = 1000;
+    let msg = ExecuteMsg::SetMintRateLimit {
+        minter: None,
+        limit: Some(MintRateLimitInfo {
+            max_per_epoch: Uint128::new(300),
+            epoch: Duration::Height(100),
+        }),
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+    let msg = ExecuteMsg::SetEmissionSchedule {
+        rate_per_block: Uint128::new(100),
+        start_height: None,
+        halving_interval: None,
+        recipients: vec![recipient(TREASURY, 100)],
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+
+    // 500이 쌓였지만 300만 발행하고 나머지는 이월
+    env.block.height += 5;
+    let info = query_emission_info(deps.as_ref(), env.clone()).unwrap();
+    assert_eq!(info.next_amount, Uint128::new(300));
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), ExecuteMsg::ReleaseEmissions {})
+        .unwrap();
+    assert_eq!(balance(&deps, TREASURY), Uint128::new(300));
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        ExecuteMsg::ReleaseEmissions {},
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MintRateExceeded {
+            remaining: Uint128::zero()
+        }
+    );
+
+    // 베스팅 신규 발행도 같은 한도 적용
+    let msg = ExecuteMsg::GrantVested {
+        recipient: ECOSYSTEM.to_string(),
+        amount: Uint128::new(1),
+        schedule: VestingSchedule {
+            start: env.block.time,
+            cliff: env.block.time,
+            end: env.block.time.plus_seconds(100),
+        },
+        mint: true,
+    };
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MintRateExceeded {
+            remaining: Uint128::zero()
+        }
+    );
+
+    // 다음 에포크에서 이월분 포함 다시 300까지 발행
+    env.block.height = 1100;
+    execute(deps.as_mut(), env.clone(), admin_info, ExecuteMsg::ReleaseEmissions {}).unwrap();
+    assert_eq!(balance(&deps, TREASURY), Uint128::new(600));
+    let info = query_emission_info(deps.as_ref(), env).unwrap();
+    assert_eq!(info.total_released, Uint128::new(600));
+}
+
+#[test]
+fn test_emission_drops_blocked_portion() {
+    let mut deps = setup();
+    let mut env = mock_env();
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+
+    let msg = ExecuteMsg::SetEmissionSchedule {
+        rate_per_block: Uint128::new(100),
+        start_height: None,
+        halving_interval: None,
+        recipients: vec![recipient(ECOSYSTEM, 70), recipient(TREASURY, 30)],
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+    let msg = ExecuteMsg::FreezeAccount {
+        address: TREASURY.to_string(),
+        reason: "investigation".to_string(),
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+
+    // 동결된 주소의 몫은 발행하지 않고 버림, 나머지는 정상 지급
+    env.block.height += 10;
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        ExecuteMsg::ReleaseEmissions {},
+    )
+    .unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "blocked" && attr.value == TREASURY));
+    assert_eq!(balance(&deps, ECOSYSTEM), Uint128::new(700));
+    assert_eq!(balance(&deps, TREASURY), Uint128::zero());
+    assert_eq!(
+        query_token_info(deps.as_ref()).unwrap().total_supply,
+        Uint128::new(1700)
+    );
+    let info = query_emission_info(deps.as_ref(), env.clone()).unwrap();
+    assert_eq!(info.total_released, Uint128::new(700));
+    assert_eq!(info.next_amount, Uint128::zero());
+
+    // 동결 해제 후에는 새로 쌓인 양만 비율대로 분배, 막혔던 몫이 다른 수령 주소로 넘어가지 않음
+    let msg = ExecuteMsg::UnfreezeAccount {
+        address: TREASURY.to_string(),
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+    env.block.height += 10;
+    execute(deps.as_mut(), env, admin_info, ExecuteMsg::ReleaseEmissions {}).unwrap();
+    assert_eq!(balance(&deps, ECOSYSTEM), Uint128::new(700 + 700));
+    assert_eq!(balance(&deps, TREASURY), Uint128::new(300));
+    assert_eq!(
+        query_token_info(deps.as_ref()).unwrap().total_supply,
+        Uint128::new(2700)
+    );
+}
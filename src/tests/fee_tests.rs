@@ -2,12 +2,11 @@
 mod tests {
     use cosmwasm_std::testing::{mock_dependencies, mock_env};
     use cosmwasm_std::{Addr, Decimal, MessageInfo, Uint128};
-    use cw20::Cw20Coin;
 
     use crate::contract::{execute, instantiate, query_balance, query_fee_config};
     use crate::error::ContractError;
-    use crate::fee::{FeeTokenType, FeeType};
-    use crate::msg::{ExecuteMsg, FeeCollectorInput, InstantiateMsg};
+    use crate::fee::{BatchFeeMode, FeeTokenType, FeeType};
+    use crate::msg::{BatchTransferItem, ExecuteMsg, FeeCollectorInput, InitialBalance, InstantiateMsg};
 
     // 테스트 상수 정의 - 수수료 수취자를 별도 주소로 분리
     const CREATOR: &str = "cosmos1vlhe6z8r7al2lyzp7n3j2vl5kd28hhrw0vxmxr";
@@ -27,13 +26,15 @@ mod tests {
             symbol: "FEE".to_string(),
             decimals: 6,
             initial_balances: vec![
-                Cw20Coin {
+                InitialBalance {
                     address: ADMIN.to_string(),
                     amount: Uint128::new(1000000000),
+                    vesting: None,
                 },
-                Cw20Coin {
+                InitialBalance {
                     address: USER1.to_string(),
                     amount: Uint128::new(1000000000),
+                    vesting: None,
                 },
             ],
             marketing: None,
@@ -60,6 +61,7 @@ mod tests {
                 percentage: "1.0".to_string(), // 100%
             }],
             is_active: true,
+            batch_fee_mode: None,
         };
 
         let admin_info = MessageInfo {
@@ -131,13 +133,15 @@ mod tests {
             symbol: "FEE".to_string(),
             decimals: 6,
             initial_balances: vec![
-                Cw20Coin {
+                InitialBalance {
                     address: ADMIN.to_string(),
                     amount: Uint128::new(1000000000),
+                    vesting: None,
                 },
-                Cw20Coin {
+                InitialBalance {
                     address: USER1.to_string(),
                     amount: Uint128::new(1000000000),
+                    vesting: None,
                 },
             ],
             marketing: None,
@@ -158,6 +162,7 @@ mod tests {
             spender: ADMIN.to_string(),
             amount: Uint128::new(200000000),
             expires: None,
+            allowed_recipients: None,
         };
         
         let user_info = MessageInfo {
@@ -178,6 +183,7 @@ mod tests {
                 percentage: "1.0".to_string(), // 100%
             }],
             is_active: true,
+            batch_fee_mode: None,
         };
         
         let admin_info = MessageInfo {
@@ -235,9 +241,10 @@ mod tests {
             symbol: "FEE".to_string(),
             decimals: 6,
             initial_balances: vec![
-                Cw20Coin {
+                InitialBalance {
                     address: ADMIN.to_string(),
                     amount: Uint128::new(1000000000),
+                    vesting: None,
                 },
             ],
             marketing: None,
@@ -266,6 +273,7 @@ mod tests {
             },
             collectors: vec![], // 비어있는 수취인 배열
             is_active: true,
+            batch_fee_mode: None,
         };
         
         let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), invalid_fee_msg).unwrap_err();
@@ -287,6 +295,7 @@ mod tests {
                 },
             ],
             is_active: true,
+            batch_fee_mode: None,
         };
         
         let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), invalid_fee_msg).unwrap_err();
@@ -313,6 +322,7 @@ mod tests {
                 },
             ],
             is_active: true,
+            batch_fee_mode: None,
         };
         
         let err = execute(deps.as_mut(), env, unauthorized_info, fee_msg).unwrap_err();
@@ -333,13 +343,15 @@ mod tests {
             symbol: "FEE".to_string(),
             decimals: 6,
             initial_balances: vec![
-                Cw20Coin {
+                InitialBalance {
                     address: ADMIN.to_string(),
                     amount: Uint128::new(1000000000),
+                    vesting: None,
                 },
-                Cw20Coin {
+                InitialBalance {
                     address: USER1.to_string(),
                     amount: Uint128::new(1000000000),
+                    vesting: None,
                 },
             ],
             marketing: None,
@@ -372,6 +384,7 @@ mod tests {
                 },
             ],
             is_active: true,
+            batch_fee_mode: None,
         };
 
         let admin_info = MessageInfo {
@@ -421,4 +434,141 @@ mod tests {
             Uint128::new(1000000000) - transfer_amount
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_batch_transfer_fee_modes() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        // 컨트랙트 초기화
+        let msg = InstantiateMsg {
+            name: "Fee Token".to_string(),
+            symbol: "FEE".to_string(),
+            decimals: 6,
+            initial_balances: vec![
+                InitialBalance {
+                    address: ADMIN.to_string(),
+                    amount: Uint128::new(1000000000),
+                    vesting: None,
+                },
+                InitialBalance {
+                    address: USER1.to_string(),
+                    amount: Uint128::new(1000000000),
+                    vesting: None,
+                },
+            ],
+            marketing: None,
+            mint: None,
+            created_on_platform: None,
+        };
+
+        let info = MessageInfo {
+            sender: Addr::unchecked(CREATOR),
+            funds: vec![],
+        };
+        instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        let admin_info = MessageInfo {
+            sender: Addr::unchecked(ADMIN),
+            funds: vec![],
+        };
+        let user_info = MessageInfo {
+            sender: Addr::unchecked(USER1),
+            funds: vec![],
+        };
+        let batch = ExecuteMsg::BatchTransfer {
+            transfers: vec![
+                BatchTransferItem {
+                    recipient: RECIPIENT.to_string(),
+                    amount: Uint128::new(3000000),
+                },
+                BatchTransferItem {
+                    recipient: ADMIN.to_string(),
+                    amount: Uint128::new(1000000),
+                },
+            ],
+        };
+
+        // 건별 고정 수수료 100000 (2건 = 200000)
+        let set_fee_msg = ExecuteMsg::SetFeeConfig {
+            fee_type: FeeType::Fixed(Uint128::new(100000)),
+            token_type: FeeTokenType::Cw20 {
+                contract_addr: "self".to_string(),
+            },
+            collectors: vec![FeeCollectorInput {
+                address: FEE_COLLECTOR.to_string(),
+                percentage: "1.0".to_string(),
+            }],
+            is_active: true,
+            batch_fee_mode: None,
+        };
+        execute(deps.as_mut(), env.clone(), admin_info.clone(), set_fee_msg).unwrap();
+
+        let res = execute(deps.as_mut(), env.clone(), user_info.clone(), batch.clone()).unwrap();
+        let fee_amount_attr = res.attributes.iter().find(|attr| attr.key == "fee_amount");
+        assert_eq!(fee_amount_attr.unwrap().value, "200000");
+
+        let recipient_balance = query_balance(deps.as_ref(), RECIPIENT.to_string()).unwrap();
+        assert_eq!(recipient_balance.balance, Uint128::new(2900000));
+        let admin_balance = query_balance(deps.as_ref(), ADMIN.to_string()).unwrap();
+        assert_eq!(admin_balance.balance, Uint128::new(1000900000));
+        let fee_collector_balance = query_balance(deps.as_ref(), FEE_COLLECTOR.to_string()).unwrap();
+        assert_eq!(fee_collector_balance.balance, Uint128::new(200000));
+        let sender_balance = query_balance(deps.as_ref(), USER1.to_string()).unwrap();
+        assert_eq!(sender_balance.balance, Uint128::new(996000000));
+
+        // 합계 기준 고정 수수료: 한 번만 부과되고 금액 비율로 분담
+        let set_fee_msg = ExecuteMsg::SetFeeConfig {
+            fee_type: FeeType::Fixed(Uint128::new(100000)),
+            token_type: FeeTokenType::Cw20 {
+                contract_addr: "self".to_string(),
+            },
+            collectors: vec![FeeCollectorInput {
+                address: FEE_COLLECTOR.to_string(),
+                percentage: "1.0".to_string(),
+            }],
+            is_active: true,
+            batch_fee_mode: Some(BatchFeeMode::Total),
+        };
+        execute(deps.as_mut(), env.clone(), admin_info, set_fee_msg).unwrap();
+        let fee_response = query_fee_config(deps.as_ref()).unwrap();
+        assert_eq!(fee_response.batch_fee_mode, BatchFeeMode::Total);
+
+        let res = execute(deps.as_mut(), env.clone(), user_info.clone(), batch).unwrap();
+        let fee_amount_attr = res.attributes.iter().find(|attr| attr.key == "fee_amount");
+        assert_eq!(fee_amount_attr.unwrap().value, "100000");
+
+        let recipient_balance = query_balance(deps.as_ref(), RECIPIENT.to_string()).unwrap();
+        assert_eq!(recipient_balance.balance, Uint128::new(2900000 + 2925000));
+        let admin_balance = query_balance(deps.as_ref(), ADMIN.to_string()).unwrap();
+        assert_eq!(admin_balance.balance, Uint128::new(1000900000 + 975000));
+        let fee_collector_balance = query_balance(deps.as_ref(), FEE_COLLECTOR.to_string()).unwrap();
+        assert_eq!(fee_collector_balance.balance, Uint128::new(300000));
+
+        // 잔액 부족 시 전체 실패
+        let batch = ExecuteMsg::BatchTransfer {
+            transfers: vec![BatchTransferItem {
+                recipient: RECIPIENT.to_string(),
+                amount: Uint128::new(2000000000),
+            }],
+        };
+        let err = execute(deps.as_mut(), env.clone(), user_info.clone(), batch).unwrap_err();
+        assert_eq!(err, ContractError::InsufficientFunds {});
+
+        // 모든 건이 0이면 거부 (합계 기준 분담 시 0으로 나누지 않음)
+        let batch = ExecuteMsg::BatchTransfer {
+            transfers: vec![
+                BatchTransferItem {
+                    recipient: RECIPIENT.to_string(),
+                    amount: Uint128::zero(),
+                },
+                BatchTransferItem {
+                    recipient: ADMIN.to_string(),
+                    amount: Uint128::zero(),
+                },
+            ],
+        };
+        let err = execute(deps.as_mut(), env, user_info, batch).unwrap_err();
+        assert_eq!(err, ContractError::InvalidAmount {});
+    }
+}
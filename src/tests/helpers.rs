@@ -0,0 +1,41 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{Addr, MessageInfo, OwnedDeps, Uint128};
+
+use crate::contract::instantiate;
+use crate::msg::{InitialBalance, InstantiateMsg};
+
+// 에스크로 테스트 공용 계정. 조회 시 주소 검증을 하므로 MockApi 주소 사용
+pub struct Accounts {
+    pub sender: Addr,
+    pub recipient: Addr,
+    pub other: Addr,
+}
+
+/// sender에게 1000000을 지급하고 sender를 관리자로 초기화
+pub fn setup() -> (OwnedDeps<MockStorage, MockApi, MockQuerier>, Accounts) {
+    let mut deps = mock_dependencies();
+    let accounts = Accounts {
+        sender: deps.api.addr_make("sender"),
+        recipient: deps.api.addr_make("recipient"),
+        other: deps.api.addr_make("other"),
+    };
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: accounts.sender.to_string(),
+            amount: Uint128::new(1000000),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: None,
+        created_on_platform: None,
+    };
+    let info = MessageInfo {
+        sender: accounts.other.clone(),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+    (deps, accounts)
+}
@@ -0,0 +1,145 @@
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{HexBinary, MessageInfo, Uint128};
+use cw20::Expiration;
+use sha2::{Digest, Sha256};
+
+use crate::contract::{execute, query_balance};
+use crate::error::ContractError;
+use crate::htlc::{query_htlc, query_htlcs_by_recipient, query_htlcs_by_sender};
+use crate::msg::ExecuteMsg;
+use crate::tests::helpers::setup;
+
+fn hashlock(preimage: &[u8]) -> HexBinary {
+    HexBinary::from(Sha256::digest(preimage).as_slice())
+}
+
+#[test]
+fn test_htlc_claim() {
+    let (mut deps, accounts) = setup();
+    let env = mock_env();
+    let sender_info = MessageInfo {
+        sender: accounts.sender.clone(),
+        funds: vec![],
+    };
+
+    // 잘못된 hashlock 길이 거부
+    let msg = ExecuteMsg::LockHtlc {
+        recipient: accounts.recipient.to_string(),
+        amount: Uint128::new(100000),
+        hashlock: HexBinary::from(b"short".as_slice()),
+        timelock: Expiration::AtHeight(env.block.height + 100),
+    };
+    let err = execute(deps.as_mut(), env.clone(), sender_info.clone(), msg).unwrap_err();
+    assert_eq!(err, ContractError::InvalidHashlock {});
+
+    let msg = ExecuteMsg::LockHtlc {
+        recipient: accounts.recipient.to_string(),
+        amount: Uint128::new(100000),
+        hashlock: hashlock(b"swap secret"),
+        timelock: Expiration::AtHeight(env.block.height + 100),
+    };
+    execute(deps.as_mut(), env.clone(), sender_info.clone(), msg.clone()).unwrap();
+    execute(deps.as_mut(), env.clone(), sender_info, msg).unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), accounts.sender.to_string()).unwrap().balance,
+        Uint128::new(800000)
+    );
+
+    // sender/recipient 인덱스 조회
+    let by_sender =
+        query_htlcs_by_sender(deps.as_ref(), accounts.sender.to_string(), None, None).unwrap();
+    assert_eq!(by_sender.htlcs.len(), 2);
+    let by_recipient =
+        query_htlcs_by_recipient(deps.as_ref(), accounts.recipient.to_string(), Some(1), None)
+            .unwrap();
+    assert_eq!(by_recipient.htlcs.len(), 1);
+    assert_eq!(by_recipient.htlcs[0].id, 2);
+
+    // 틀린 preimage 거부
+    let other_info = MessageInfo {
+        sender: accounts.other.clone(),
+        funds: vec![],
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        other_info.clone(),
+        ExecuteMsg::ClaimHtlc {
+            id: 1,
+            preimage: HexBinary::from(b"wrong".as_slice()),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidPreimage {});
+
+    // timelock 전에는 환불 불가
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        other_info.clone(),
+        ExecuteMsg::RefundHtlc { id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::HtlcNotExpired {});
+
+    // 올바른 preimage면 누가 호출하든 recipient에게 지급
+    let res = execute(
+        deps.as_mut(),
+        env,
+        other_info,
+        ExecuteMsg::ClaimHtlc {
+            id: 1,
+            preimage: HexBinary::from(b"swap secret".as_slice()),
+        },
+    )
+    .unwrap();
+    let preimage_attr = res.attributes.iter().find(|attr| attr.key == "preimage").unwrap();
+    assert_eq!(preimage_attr.value, HexBinary::from(b"swap secret".as_slice()).to_hex());
+    assert_eq!(
+        query_balance(deps.as_ref(), accounts.recipient.to_string()).unwrap().balance,
+        Uint128::new(100000)
+    );
+    assert!(query_htlc(deps.as_ref(), 1).is_err());
+    let by_sender =
+        query_htlcs_by_sender(deps.as_ref(), accounts.sender.to_string(), None, None).unwrap();
+    assert_eq!(by_sender.htlcs.len(), 1);
+}
+
+#[test]
+fn test_htlc_refund() {
+    let (mut deps, accounts) = setup();
+    let mut env = mock_env();
+    let sender_info = MessageInfo {
+        sender: accounts.sender.clone(),
+        funds: vec![],
+    };
+
+    let msg = ExecuteMsg::LockHtlc {
+        recipient: accounts.recipient.to_string(),
+        amount: Uint128::new(100000),
+        hashlock: hashlock(b"swap secret"),
+        timelock: Expiration::AtHeight(env.block.height + 100),
+    };
+    execute(deps.as_mut(), env.clone(), sender_info.clone(), msg).unwrap();
+
+    // timelock 이후에는 청구 불가, 환불만 가능
+    env.block.height += 100;
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        sender_info.clone(),
+        ExecuteMsg::ClaimHtlc {
+            id: 1,
+            preimage: HexBinary::from(b"swap secret".as_slice()),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::HtlcExpired {});
+
+    execute(deps.as_mut(), env, sender_info, ExecuteMsg::RefundHtlc { id: 1 }).unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), accounts.sender.to_string()).unwrap().balance,
+        Uint128::new(1000000)
+    );
+    assert!(query_htlc(deps.as_ref(), 1).is_err());
+}
@@ -1,11 +1,30 @@
 use cosmwasm_std::testing::{mock_dependencies, mock_env};
-use cosmwasm_std::{Addr, MessageInfo, Uint128};
-use cw20::Cw20Coin;
+use cosmwasm_std::{
+    to_json_binary, Addr, CosmosMsg, Decimal, MessageInfo, Reply, ReplyOn, SubMsgResult, Uint128,
+    WasmMsg,
+};
+use cw20::{Cw20Coin, Cw20ExecuteMsg, Cw20ReceiveMsg, Expiration};
+use cw_utils::Duration;
 
-use crate::contract::{execute, instantiate, query_balance, query_fee_granter};
+use crate::burns::{query_burn_receipt, query_burn_receipts_by_burner};
+use crate::contract::{
+    execute, instantiate, migrate, query_balance, query_fee_granter, query_minter, reply,
+};
+use crate::enumerable::{query_frozen_accounts, query_minters};
+use crate::fee::{FeeTokenType, FeeType};
+use crate::hooks::{query_hooks, BALANCE_HOOK_REPLY_ID};
+use crate::limits::{query_next_transfer, query_transfer_limits};
+use crate::memo::query_memo_required;
+use crate::minters::{query_mint_capacity, query_pending_minter};
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMarketingInfo, InstantiateMsg};
-use crate::state::{EXTENDED_INFO, MARKETING_INFO};
+use cw_controllers::HookError;
+use crate::msg::{
+    BalanceChangeHookMsg, ConfigInfo, ExecuteMsg, FeeCollectorInput, InitialBalance, InstantiateMarketingInfo, InstantiateMsg,
+    MemoInfo, MemoPayload, MigrateMsg, MintRateLimitInfo, MinterResponse,
+};
+use crate::pause::{query_pause_status, PausableOperation};
+use crate::state::{CONFIG, EXTENDED_INFO, MARKETING_INFO, TOKEN_INFO};
+use crate::supply::{query_supply_cap, query_total_supply_at_height, seed_total_supply_history};
 
 // 테스트 상수 정의
 const CREATOR: &str = "cosmos1vlhe6z8r7al2lyzp7n3j2vl5kd28hhrw0vxmxr";
@@ -23,9 +42,10 @@ fn proper_initialization_with_marketing() {
         name: "iUPPITER".to_string(),
         symbol: "iUP".to_string(),
         decimals: 6,
-        initial_balances: vec![Cw20Coin {
+        initial_balances: vec![InitialBalance {
             address: ADMIN.to_string(),
             amount: Uint128::new(1000000000000),
+            vesting: None,
         }],
         marketing: Some(InstantiateMarketingInfo {
             project: Some("iUPPITER Project".to_string()),
@@ -65,9 +85,10 @@ fn test_transfer() {
         name: "iUPPITER".to_string(),
         symbol: "iUP".to_string(),
         decimals: 6,
-        initial_balances: vec![Cw20Coin {
+        initial_balances: vec![InitialBalance {
             address: ADMIN.to_string(),
             amount: Uint128::new(1000000000000),
+            vesting: None,
         }],
         marketing: Some(InstantiateMarketingInfo {
             project: Some("iUPPITER Project".to_string()),
@@ -115,9 +136,10 @@ fn test_fee_granter() {
         name: "iUPPITER".to_string(),
         symbol: "iUP".to_string(),
         decimals: 6,
-        initial_balances: vec![Cw20Coin {
+        initial_balances: vec![InitialBalance {
             address: ADMIN.to_string(),
             amount: Uint128::new(1000000000000),
+            vesting: None,
         }],
         marketing: Some(InstantiateMarketingInfo {
             project: Some("iUPPITER Project".to_string()),
@@ -179,9 +201,10 @@ fn test_unauthorized_fee_granter() {
         name: "iUPPITER".to_string(),
         symbol: "iUP".to_string(),
         decimals: 6,
-        initial_balances: vec![Cw20Coin {
+        initial_balances: vec![InitialBalance {
             address: ADMIN.to_string(),
             amount: Uint128::new(1000000000000),
+            vesting: None,
         }],
         marketing: Some(InstantiateMarketingInfo {
             project: Some("iUPPITER Project".to_string()),
@@ -228,9 +251,10 @@ fn test_upgrade_admin() {
         name: "iUPPITER".to_string(),
         symbol: "iUP".to_string(),
         decimals: 6,
-        initial_balances: vec![Cw20Coin {
+        initial_balances: vec![InitialBalance {
             address: ADMIN.to_string(),
             amount: Uint128::new(1000000000000),
+            vesting: None,
         }],
         marketing: Some(InstantiateMarketingInfo {
             project: Some("iUPPITER Project".to_string()),
@@ -262,4 +286,1670 @@ fn test_upgrade_admin() {
 
     let res = execute(deps.as_mut(), env.clone(), admin_info, set_admin_msg).unwrap();
     assert_eq!(2, res.attributes.len());
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_transfer_and_send_with_memo() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(1000000000000),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: None,
+        created_on_platform: None,
+    };
+    let info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+    let memo = MemoInfo {
+        action: "buy_item".to_string(),
+        player: "player-42".to_string(),
+        cost: "100".to_string(),
+    };
+
+    // 메모 포함 전송
+    let msg = ExecuteMsg::TransferWithMemo {
+        recipient: USER1.to_string(),
+        amount: Uint128::new(100),
+        memo: memo.clone(),
+    };
+    let res = execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+    assert_eq!(7, res.attributes.len());
+    let memo_player = res.attributes.iter().find(|attr| attr.key == "memo_player");
+    assert_eq!(memo_player.unwrap().value, "player-42");
+
+    // 메모 포함 Send - 수신 컨트랙트에 메모가 함께 전달됨
+    let shop = deps.api.addr_make("item_shop").to_string();
+    let inner = to_json_binary(&Cw20ExecuteMsg::Burn { amount: Uint128::one() }).unwrap();
+    let msg = ExecuteMsg::SendWithMemo {
+        contract: shop.clone(),
+        amount: Uint128::new(100),
+        msg: inner.clone(),
+        memo: memo.clone(),
+    };
+    let res = execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+    let memo_action = res.attributes.iter().find(|attr| attr.key == "memo_action");
+    assert_eq!(memo_action.unwrap().value, "buy_item");
+    let payload = to_json_binary(&MemoPayload {
+        memo: memo.clone(),
+        msg: inner,
+    })
+    .unwrap();
+    let expected = Cw20ReceiveMsg {
+        sender: ADMIN.to_string(),
+        amount: Uint128::new(100),
+        msg: payload,
+    }
+    .into_json_binary()
+    .unwrap();
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, msg, .. }) => {
+            assert_eq!(contract_addr, &shop);
+            assert_eq!(msg, &expected);
+        }
+        _ => panic!("Expected wasm execute message"),
+    }
+
+    // 메모 길이 제한
+    let msg = ExecuteMsg::TransferWithMemo {
+        recipient: USER1.to_string(),
+        amount: Uint128::new(100),
+        memo: MemoInfo {
+            action: "buy_item".to_string(),
+            player: "p".repeat(65),
+            cost: "100".to_string(),
+        },
+    };
+    let err = execute(deps.as_mut(), env, admin_info, msg).unwrap_err();
+    match err {
+        ContractError::InvalidMemo { .. } => {}
+        _ => panic!("Expected InvalidMemo error"),
+    }
+}
+
+#[test]
+fn test_memo_required_destination() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(1000000000000),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: None,
+        created_on_platform: None,
+    };
+    let info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+    let user_info = MessageInfo {
+        sender: Addr::unchecked(USER1),
+        funds: vec![],
+    };
+
+    // USER1이 스스로 메모 필수 주소로 등록
+    let msg = ExecuteMsg::SetMemoRequired {
+        address: None,
+        required: true,
+    };
+    execute(deps.as_mut(), env.clone(), user_info.clone(), msg).unwrap();
+    assert!(query_memo_required(deps.as_ref(), USER1.to_string()).unwrap().required);
+
+    // 다른 주소는 관리자만 등록 가능
+    let msg = ExecuteMsg::SetMemoRequired {
+        address: Some(FEE_GRANTER.to_string()),
+        required: true,
+    };
+    let err = execute(deps.as_mut(), env.clone(), user_info.clone(), msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+    assert!(query_memo_required(deps.as_ref(), FEE_GRANTER.to_string()).unwrap().required);
+
+    // 메모 없는 전송은 거부
+    let msg = ExecuteMsg::Transfer {
+        recipient: USER1.to_string(),
+        amount: Uint128::new(100),
+    };
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MemoRequired {
+            recipient: USER1.to_string()
+        }
+    );
+
+    // 허용량 기반 전송도 마찬가지
+    let msg = ExecuteMsg::IncreaseAllowance {
+        spender: USER1.to_string(),
+        amount: Uint128::new(1000),
+        expires: None,
+        allowed_recipients: None,
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+    let msg = ExecuteMsg::TransferFrom {
+        owner: ADMIN.to_string(),
+        recipient: FEE_GRANTER.to_string(),
+        amount: Uint128::new(100),
+    };
+    let err = execute(deps.as_mut(), env.clone(), user_info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MemoRequired {
+            recipient: FEE_GRANTER.to_string()
+        }
+    );
+    let msg = ExecuteMsg::TransferFromWithMemo {
+        owner: ADMIN.to_string(),
+        recipient: FEE_GRANTER.to_string(),
+        amount: Uint128::new(100),
+        memo: MemoInfo {
+            action: "deposit".to_string(),
+            player: "player-7".to_string(),
+            cost: "100".to_string(),
+        },
+    };
+    execute(deps.as_mut(), env.clone(), user_info.clone(), msg).unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), FEE_GRANTER.to_string()).unwrap().balance,
+        Uint128::new(100)
+    );
+
+    // 메모가 있으면 허용
+    let msg = ExecuteMsg::TransferWithMemo {
+        recipient: USER1.to_string(),
+        amount: Uint128::new(100),
+        memo: MemoInfo {
+            action: "deposit".to_string(),
+            player: "player-7".to_string(),
+            cost: "100".to_string(),
+        },
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), USER1.to_string()).unwrap().balance,
+        Uint128::new(100)
+    );
+
+    // 해제 후에는 일반 전송 가능
+    let msg = ExecuteMsg::SetMemoRequired {
+        address: None,
+        required: false,
+    };
+    execute(deps.as_mut(), env.clone(), user_info, msg).unwrap();
+    let msg = ExecuteMsg::Transfer {
+        recipient: USER1.to_string(),
+        amount: Uint128::new(100),
+    };
+    execute(deps.as_mut(), env, admin_info, msg).unwrap();
+}
+
+#[test]
+fn test_freeze_account() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(1000000000000),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: None,
+        created_on_platform: None,
+    };
+    let info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+    let user_info = MessageInfo {
+        sender: Addr::unchecked(USER1),
+        funds: vec![],
+    };
+    let transfer = |recipient: &str| ExecuteMsg::Transfer {
+        recipient: recipient.to_string(),
+        amount: Uint128::new(100),
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), transfer(USER1)).unwrap();
+
+    // 관리자만 동결 가능
+    let freeze = ExecuteMsg::FreezeAccount {
+        address: USER1.to_string(),
+        reason: "exploit".to_string(),
+    };
+    let err = execute(deps.as_mut(), env.clone(), user_info.clone(), freeze.clone()).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), freeze).unwrap();
+
+    let frozen = query_frozen_accounts(deps.as_ref(), None, None).unwrap();
+    assert_eq!(frozen.accounts.len(), 1);
+    assert_eq!(frozen.accounts[0].address, USER1);
+    assert_eq!(frozen.accounts[0].reason, "exploit");
+
+    // 송금, 수신 모두 불가
+    let frozen_err = ContractError::AccountFrozen {
+        address: USER1.to_string(),
+    };
+    let err = execute(deps.as_mut(), env.clone(), user_info.clone(), transfer(ADMIN)).unwrap_err();
+    assert_eq!(err, frozen_err);
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), transfer(USER1)).unwrap_err();
+    assert_eq!(err, frozen_err);
+
+    // spender로도 사용 불가
+    let msg = ExecuteMsg::IncreaseAllowance {
+        spender: USER1.to_string(),
+        amount: Uint128::new(1000),
+        expires: None,
+        allowed_recipients: None,
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+    let msg = ExecuteMsg::TransferFrom {
+        owner: ADMIN.to_string(),
+        recipient: FEE_GRANTER.to_string(),
+        amount: Uint128::new(100),
+    };
+    let err = execute(deps.as_mut(), env.clone(), user_info.clone(), msg.clone()).unwrap_err();
+    assert_eq!(err, frozen_err);
+
+    // 해제 후 정상 동작
+    let unfreeze = ExecuteMsg::UnfreezeAccount {
+        address: USER1.to_string(),
+    };
+    execute(deps.as_mut(), env.clone(), admin_info, unfreeze).unwrap();
+    assert!(query_frozen_accounts(deps.as_ref(), None, None)
+        .unwrap()
+        .accounts
+        .is_empty());
+    execute(deps.as_mut(), env.clone(), user_info.clone(), msg).unwrap();
+    execute(deps.as_mut(), env, user_info, transfer(ADMIN)).unwrap();
+}
+
+#[test]
+fn test_pause_operations() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(1000000000000),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: None,
+        created_on_platform: None,
+    };
+    let info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+    let transfer = ExecuteMsg::Transfer {
+        recipient: USER1.to_string(),
+        amount: Uint128::new(100),
+    };
+
+    // 관리자만 정지 가능
+    let pause = ExecuteMsg::Pause {
+        operations: Some(vec![PausableOperation::Transfer]),
+    };
+    let user_info = MessageInfo {
+        sender: Addr::unchecked(USER1),
+        funds: vec![],
+    };
+    let err = execute(deps.as_mut(), env.clone(), user_info, pause.clone()).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // 전송만 정지: 허용량 변경은 가능
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), pause).unwrap();
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), transfer.clone()).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Paused {
+            operation: "transfer".to_string()
+        }
+    );
+    let msg = ExecuteMsg::IncreaseAllowance {
+        spender: USER1.to_string(),
+        amount: Uint128::new(1000),
+        expires: None,
+        allowed_recipients: None,
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+
+    // 전체 정지 중에도 관리자 설정 변경 가능
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        ExecuteMsg::Pause { operations: None },
+    )
+    .unwrap();
+    let status = query_pause_status(deps.as_ref()).unwrap();
+    assert!(status.fully_paused);
+    let msg = ExecuteMsg::SetFeeConfig {
+        fee_type: FeeType::Percentage(Decimal::percent(1)),
+        token_type: FeeTokenType::Cw20 {
+            contract_addr: "self".to_string(),
+        },
+        collectors: vec![FeeCollectorInput {
+            address: FEE_GRANTER.to_string(),
+            percentage: "1.0".to_string(),
+        }],
+        is_active: true,
+        batch_fee_mode: None,
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+
+    // 수수료 지급만 남기고 해제: 수수료가 붙는 전송은 차단
+    let unpause = ExecuteMsg::Unpause {
+        operations: Some(vec![
+            PausableOperation::Transfer,
+            PausableOperation::Send,
+            PausableOperation::Mint,
+            PausableOperation::Burn,
+            PausableOperation::Allowances,
+        ]),
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), unpause).unwrap();
+    let status = query_pause_status(deps.as_ref()).unwrap();
+    assert_eq!(status.paused_operations, vec![PausableOperation::FeePayouts]);
+    assert!(!status.fully_paused);
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), transfer.clone()).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Paused {
+            operation: "fee_payouts".to_string()
+        }
+    );
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        ExecuteMsg::Burn {
+            amount: Uint128::new(100),
+        },
+    )
+    .unwrap();
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        ExecuteMsg::Unpause { operations: None },
+    )
+    .unwrap();
+    assert!(query_pause_status(deps.as_ref())
+        .unwrap()
+        .paused_operations
+        .is_empty());
+    execute(deps.as_mut(), env, admin_info, transfer).unwrap();
+}
+
+#[test]
+fn test_transfer_limits() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(1000000000000),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: None,
+        created_on_platform: None,
+    };
+    let info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+    let user_info = MessageInfo {
+        sender: Addr::unchecked(USER1),
+        funds: vec![],
+    };
+    let transfer = |recipient: &str, amount: u128| ExecuteMsg::Transfer {
+        recipient: recipient.to_string(),
+        amount: Uint128::new(amount),
+    };
+
+    // 관리자만 설정 가능
+    let msg = ExecuteMsg::SetTransferLimits {
+        max_tx_amount: Some(Uint128::new(1000)),
+        max_wallet_balance: Some(Uint128::new(1500)),
+        exempt: vec![ADMIN.to_string()],
+    };
+    let err = execute(deps.as_mut(), env.clone(), user_info.clone(), msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+
+    let limits = query_transfer_limits(deps.as_ref()).unwrap();
+    assert_eq!(limits.max_tx_amount, Some(Uint128::new(1000)));
+    assert_eq!(limits.exempt, vec![ADMIN.to_string()]);
+
+    // 면제 주소는 1회 한도 없이 보낼 수 있지만 수신자 지갑 한도는 적용
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), transfer(USER1, 2000)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ExceedsMaxWalletBalance {
+            address: USER1.to_string(),
+            max: Uint128::new(1500),
+        }
+    );
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), transfer(USER1, 1500)).unwrap();
+
+    // 일반 주소는 1회 한도 적용
+    let err = execute(deps.as_mut(), env.clone(), user_info.clone(), transfer(FEE_GRANTER, 1001)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ExceedsMaxTransaction {
+            max: Uint128::new(1000)
+        }
+    );
+    execute(deps.as_mut(), env.clone(), user_info.clone(), transfer(FEE_GRANTER, 1000)).unwrap();
+
+    // 누적 잔액 기준으로 지갑 한도 확인
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), transfer(FEE_GRANTER, 501)).unwrap_err();
+    assert!(matches!(err, ContractError::ExceedsMaxWalletBalance { .. }));
+    execute(deps.as_mut(), env.clone(), admin_info, transfer(FEE_GRANTER, 500)).unwrap();
+
+    // 면제 주소로는 지갑 한도 없이 수신
+    execute(deps.as_mut(), env, user_info, transfer(ADMIN, 500)).unwrap();
+}
+
+#[test]
+fn test_transfer_cooldown() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(1000000000000),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: None,
+        created_on_platform: None,
+    };
+    let info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+    let user_info = MessageInfo {
+        sender: Addr::unchecked(USER1),
+        funds: vec![],
+    };
+    let transfer = |recipient: &str| ExecuteMsg::Transfer {
+        recipient: recipient.to_string(),
+        amount: Uint128::new(100),
+    };
+
+    // 관리자만 설정 가능
+    let msg = ExecuteMsg::SetTransferCooldown {
+        cooldown: Some(Duration::Height(3)),
+    };
+    let err = execute(deps.as_mut(), env.clone(), user_info.clone(), msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+
+    // 전송 한도 설정은 쿨다운을 덮어쓰지 않고, 한도 면제 주소도 쿨다운은 적용
+    let msg = ExecuteMsg::SetTransferLimits {
+        max_tx_amount: None,
+        max_wallet_balance: None,
+        exempt: vec![ADMIN.to_string()],
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+    assert_eq!(
+        query_transfer_limits(deps.as_ref()).unwrap().cooldown,
+        Some(Duration::Height(3))
+    );
+    let msg = ExecuteMsg::Transfer {
+        recipient: USER1.to_string(),
+        amount: Uint128::new(1000),
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), transfer(USER1)).unwrap_err();
+    assert!(matches!(err, ContractError::CooldownActive { .. }));
+
+    // 첫 출금은 바로 가능
+    let next = query_next_transfer(deps.as_ref(), env.clone(), USER1.to_string()).unwrap();
+    assert_eq!(next.next_allowed, None);
+    execute(deps.as_mut(), env.clone(), user_info.clone(), transfer(FEE_GRANTER)).unwrap();
+
+    // 같은 블록의 두 번째 출금은 거부
+    let next_height = env.block.height + 3;
+    let err = execute(deps.as_mut(), env.clone(), user_info.clone(), transfer(FEE_GRANTER)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CooldownActive {
+            next: Expiration::AtHeight(next_height)
+        }
+    );
+    let next = query_next_transfer(deps.as_ref(), env.clone(), USER1.to_string()).unwrap();
+    assert_eq!(next.next_allowed, Some(Expiration::AtHeight(next_height)));
+
+    // 허용량 기반 전송도 owner 기준으로 적용
+    let msg = ExecuteMsg::IncreaseAllowance {
+        spender: ADMIN.to_string(),
+        amount: Uint128::new(1000),
+        expires: None,
+        allowed_recipients: None,
+    };
+    execute(deps.as_mut(), env.clone(), user_info.clone(), msg).unwrap();
+    let msg = ExecuteMsg::TransferFrom {
+        owner: USER1.to_string(),
+        recipient: FEE_GRANTER.to_string(),
+        amount: Uint128::new(100),
+    };
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::CooldownActive { .. }));
+
+    // 예약 전송도 출금으로 간주
+    let msg = ExecuteMsg::ScheduleTransfer {
+        recipient: FEE_GRANTER.to_string(),
+        amount: Uint128::new(100),
+        release_at: Expiration::AtHeight(env.block.height + 10),
+        cancellable_until: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), user_info.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::CooldownActive { .. }));
+
+    // 쿨다운이 지나면 다시 가능
+    env.block.height = next_height;
+    execute(deps.as_mut(), env.clone(), user_info.clone(), transfer(FEE_GRANTER)).unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), FEE_GRANTER.to_string()).unwrap().balance,
+        Uint128::new(200)
+    );
+
+    // 쿨다운 해제
+    let msg = ExecuteMsg::SetTransferCooldown { cooldown: None };
+    execute(deps.as_mut(), env.clone(), admin_info, msg).unwrap();
+    assert_eq!(query_transfer_limits(deps.as_ref()).unwrap().cooldown, None);
+    execute(deps.as_mut(), env, user_info, transfer(FEE_GRANTER)).unwrap();
+}
+
+#[test]
+fn test_balance_change_hooks() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    // 훅 주소는 검증을 거치므로 MockApi 주소 사용
+    let staking = deps.api.addr_make("staking");
+    let analytics = deps.api.addr_make("analytics");
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(1000),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: None,
+        created_on_platform: None,
+    };
+    let info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+    let user_info = MessageInfo {
+        sender: Addr::unchecked(USER1),
+        funds: vec![],
+    };
+
+    // 관리자만 등록 가능
+    let add_hook = |address: &Addr, revert_on_error: bool| ExecuteMsg::AddHook {
+        address: address.to_string(),
+        revert_on_error,
+    };
+    let err = execute(deps.as_mut(), env.clone(), user_info.clone(), add_hook(&staking, true))
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), add_hook(&staking, true)).unwrap();
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), add_hook(&analytics, false)).unwrap();
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), add_hook(&staking, false))
+        .unwrap_err();
+    assert_eq!(err, ContractError::Hook(HookError::HookAlreadyRegistered {}));
+
+    let hooks = query_hooks(deps.as_ref()).unwrap().hooks;
+    assert_eq!(hooks.len(), 2);
+    assert!(hooks[0].revert_on_error);
+    assert!(!hooks[1].revert_on_error);
+
+    // 전송 시 양쪽 잔액 변경을 모든 훅에 전달
+    let msg = ExecuteMsg::Transfer {
+        recipient: USER1.to_string(),
+        amount: Uint128::new(100),
+    };
+    let res = execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+    assert_eq!(res.messages.len(), 2);
+    let expected = BalanceChangeHookMsg {
+        changes: vec![
+            (ADMIN.to_string(), Uint128::new(1000), Uint128::new(900)),
+            (USER1.to_string(), Uint128::zero(), Uint128::new(100)),
+        ],
+    };
+    assert_eq!(
+        res.messages[0].msg,
+        expected.clone().into_cosmos_msg(&staking).unwrap()
+    );
+    assert_eq!(res.messages[0].reply_on, ReplyOn::Never);
+    assert_eq!(res.messages[1].msg, expected.into_cosmos_msg(&analytics).unwrap());
+    assert_eq!(res.messages[1].reply_on, ReplyOn::Error);
+    assert_eq!(res.messages[1].id, BALANCE_HOOK_REPLY_ID);
+
+    // 되돌림 설정이 없는 훅의 실패는 기록만 하고 무시
+    let failed = Reply {
+        id: BALANCE_HOOK_REPLY_ID,
+        payload: res.messages[1].payload.clone(),
+        gas_used: 0,
+        result: SubMsgResult::Err("out of gas".to_string()),
+    };
+    let res = reply(deps.as_mut(), env.clone(), failed).unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "hook" && attr.value == analytics.as_str()));
+
+    // 해제 후에는 남은 훅만 호출
+    let msg = ExecuteMsg::RemoveHook {
+        address: staking.to_string(),
+    };
+    execute(deps.as_mut(), env.clone(), admin_info, msg).unwrap();
+    let res = execute(
+        deps.as_mut(),
+        env,
+        user_info,
+        ExecuteMsg::Burn {
+            amount: Uint128::new(40),
+        },
+    )
+    .unwrap();
+    let expected = BalanceChangeHookMsg {
+        changes: vec![(USER1.to_string(), Uint128::new(100), Uint128::new(60))],
+    };
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].msg, expected.into_cosmos_msg(&analytics).unwrap());
+}
+
+#[test]
+fn test_minter_registry() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    // 발행 수신자는 주소 검증을 거치므로 MockApi 주소 사용
+    let recipient = deps.api.addr_make("recipient");
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(100),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: Some(MinterResponse {
+            minter: CREATOR.to_string(),
+            cap: Some(Uint128::new(1000)),
+        }),
+        created_on_platform: None,
+    };
+    let info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let info_of = |sender: &str| MessageInfo {
+        sender: Addr::unchecked(sender),
+        funds: vec![],
+    };
+    let mint = |amount: u128| ExecuteMsg::Mint {
+        recipient: recipient.to_string(),
+        amount: Uint128::new(amount),
+    };
+
+    // 관리자만 minter 등록 가능
+    let msg = ExecuteMsg::SetMinter {
+        address: USER1.to_string(),
+        allowance: Uint128::new(500),
+        expires: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), info_of(USER1), msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), info_of(ADMIN), msg).unwrap();
+    let msg = ExecuteMsg::SetMinter {
+        address: FEE_GRANTER.to_string(),
+        allowance: Uint128::new(1000),
+        expires: Some(Expiration::AtHeight(env.block.height + 10)),
+    };
+    execute(deps.as_mut(), env.clone(), info_of(ADMIN), msg).unwrap();
+
+    // 미등록 주소는 발행 불가
+    let err = execute(deps.as_mut(), env.clone(), info_of(ADMIN), mint(10)).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // 할당량 안에서만 발행
+    execute(deps.as_mut(), env.clone(), info_of(USER1), mint(300)).unwrap();
+    let err = execute(deps.as_mut(), env.clone(), info_of(USER1), mint(300)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MinterQuotaExceeded {
+            remaining: Uint128::new(200)
+        }
+    );
+    let minters = query_minters(deps.as_ref(), None, None).unwrap().minters;
+    let user1 = minters.iter().find(|m| m.address == USER1).unwrap();
+    assert_eq!(user1.minted, Uint128::new(300));
+    assert_eq!(user1.remaining, Uint128::new(200));
+
+    // 기본 minter는 할당량 없이 발행 가능
+    execute(deps.as_mut(), env.clone(), info_of(CREATOR), mint(100)).unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), recipient.to_string()).unwrap().balance,
+        Uint128::new(400)
+    );
+
+    // 할당량이 남아 있어도 전체 cap은 넘을 수 없음
+    let err = execute(deps.as_mut(), env.clone(), info_of(FEE_GRANTER), mint(600)).unwrap_err();
+    assert_eq!(err, ContractError::CannotExceedCap {});
+
+    // 해제되거나 만료된 minter는 발행 불가
+    let msg = ExecuteMsg::RemoveMinter {
+        address: USER1.to_string(),
+    };
+    execute(deps.as_mut(), env.clone(), info_of(ADMIN), msg).unwrap();
+    let err = execute(deps.as_mut(), env.clone(), info_of(USER1), mint(10)).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    env.block.height += 10;
+    let err = execute(deps.as_mut(), env, info_of(FEE_GRANTER), mint(10)).unwrap_err();
+    assert_eq!(err, ContractError::MinterExpired {});
+}
+
+#[test]
+fn test_max_supply_hard_cap() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let recipient = deps.api.addr_make("recipient");
+    let minter = deps.api.addr_make("minter");
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(100),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: Some(MinterResponse {
+            minter: minter.to_string(),
+            cap: Some(Uint128::new(1000)),
+        }),
+        created_on_platform: None,
+    };
+    let info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    // minter cap이 그대로 최대 발행량이 됨
+    let cap = query_supply_cap(deps.as_ref()).unwrap();
+    assert_eq!(cap.max_supply, Some(Uint128::new(1000)));
+    assert_eq!(cap.remaining, Some(Uint128::new(900)));
+    assert!(cap.locked);
+
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+    let update_config = |minter_cap: Option<u128>, max_supply: Option<u128>| {
+        ExecuteMsg::UpdateConfig {
+            new_config: Box::new(Some(ConfigInfo {
+                is_upgrade_allowed: true,
+                upgrade_admin: None,
+                marketing: None,
+                minter: Some(MinterResponse {
+                    minter: minter.to_string(),
+                    cap: minter_cap.map(Uint128::new),
+                }),
+                transfer_fee: None,
+                fee_collector: None,
+                max_supply: max_supply.map(Uint128::new),
+            })),
+        }
+    };
+
+    // UpdateConfig로 올리거나 해제할 수 없음
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), update_config(None, Some(2000)))
+        .unwrap_err();
+    assert_eq!(err, ContractError::CapCannotBeRaised {});
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), update_config(None, None))
+        .unwrap_err();
+    assert_eq!(err, ContractError::CapCannotBeRaised {});
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        update_config(Some(2000), Some(800)),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidConfig { .. }));
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), update_config(None, Some(800))).unwrap();
+    assert_eq!(
+        query_minter(deps.as_ref()).unwrap().unwrap().cap,
+        Some(Uint128::new(800))
+    );
+
+    // 현재 발행량 아래로는 낮출 수 없음
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        ExecuteMsg::LowerMaxSupply {
+            max_supply: Uint128::new(50),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidAmount {});
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        ExecuteMsg::LowerMaxSupply {
+            max_supply: Uint128::new(500),
+        },
+    )
+    .unwrap();
+
+    // 추가 minter도 같은 최대 발행량 적용
+    let msg = ExecuteMsg::SetMinter {
+        address: USER1.to_string(),
+        allowance: Uint128::new(1000),
+        expires: None,
+    };
+    execute(deps.as_mut(), env.clone(), admin_info, msg).unwrap();
+    let user_info = MessageInfo {
+        sender: Addr::unchecked(USER1),
+        funds: vec![],
+    };
+    let mint = |amount: u128| ExecuteMsg::Mint {
+        recipient: recipient.to_string(),
+        amount: Uint128::new(amount),
+    };
+    execute(deps.as_mut(), env.clone(), user_info.clone(), mint(400)).unwrap();
+    let err = execute(deps.as_mut(), env.clone(), user_info, mint(1)).unwrap_err();
+    assert_eq!(err, ContractError::CannotExceedCap {});
+
+    // 마이그레이션은 두 cap 중 더 엄격한 값으로 통일 (현재 발행량 아래로는 내리지 않음)
+    let mut config = CONFIG.load(deps.as_ref().storage).unwrap();
+    config.max_supply = None;
+    CONFIG.save(deps.as_mut().storage, &config).unwrap();
+    let mut token_info = TOKEN_INFO.load(deps.as_ref().storage).unwrap();
+    token_info.mint.as_mut().unwrap().cap = Some(Uint128::new(300));
+    TOKEN_INFO.save(deps.as_mut().storage, &token_info).unwrap();
+
+    migrate(deps.as_mut(), env, MigrateMsg {}).unwrap();
+    let cap = query_supply_cap(deps.as_ref()).unwrap();
+    assert_eq!(cap.max_supply, Some(Uint128::new(500)));
+    assert_eq!(cap.remaining, Some(Uint128::zero()));
+    assert_eq!(
+        query_minter(deps.as_ref()).unwrap().unwrap().cap,
+        Some(Uint128::new(500))
+    );
+}
+
+#[test]
+fn test_mint_rate_limits() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let recipient = deps.api.addr_make("recipient");
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(1000),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: Some(MinterResponse {
+            minter: CREATOR.to_string(),
+            cap: None,
+        }),
+        created_on_platform: None,
+    };
+    let creator_info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), creator_info.clone(), msg).unwrap();
+
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+    let set_limit = |minter: Option<&str>, limit: Option<(u128, Duration)>| {
+        ExecuteMsg::SetMintRateLimit {
+            minter: minter.map(str::to_string),
+            limit: limit.map(|(max, epoch)| MintRateLimitInfo {
+                max_per_epoch: Uint128::new(max),
+                epoch,
+            }),
+        }
+    };
+
+    // 관리자만 설정 가능, 길이 0인 에포크는 거부
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        creator_info.clone(),
+        set_limit(None, Some((100, Duration::Height(10)))),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        set_limit(None, Some((100, Duration::Time(0)))),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidMintRateLimit { .. }));
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        set_limit(None, Some((100, Duration::Height(10)))),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        set_limit(Some(USER1), Some((30, Duration::Time(60)))),
+    )
+    .unwrap();
+    let msg = ExecuteMsg::SetMinter {
+        address: USER1.to_string(),
+        allowance: Uint128::new(1000),
+        expires: None,
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+
+    let mint = |amount: u128| ExecuteMsg::Mint {
+        recipient: recipient.to_string(),
+        amount: Uint128::new(amount),
+    };
+
+    // 기본 minter도 전체 한도 적용
+    execute(deps.as_mut(), env.clone(), creator_info.clone(), mint(80)).unwrap();
+    let err = execute(deps.as_mut(), env.clone(), creator_info.clone(), mint(30)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MintRateExceeded {
+            remaining: Uint128::new(20)
+        }
+    );
+    let capacity = query_mint_capacity(deps.as_ref(), env.clone(), None).unwrap();
+    let global = capacity.global.unwrap();
+    assert_eq!(global.minted, Uint128::new(80));
+    assert_eq!(global.remaining, Uint128::new(20));
+    assert_eq!(
+        global.resets_at,
+        Expiration::AtHeight((env.block.height / 10 + 1) * 10)
+    );
+    assert_eq!(capacity.minter, None);
+    assert_eq!(capacity.remaining, Some(Uint128::new(20)));
+
+    // 다음 에포크에서 전체 한도 초기화, minter별 한도가 더 작으면 그 값 적용
+    env.block.height += 10;
+    let user_info = MessageInfo {
+        sender: Addr::unchecked(USER1),
+        funds: vec![],
+    };
+    execute(deps.as_mut(), env.clone(), user_info.clone(), mint(30)).unwrap();
+    let err = execute(deps.as_mut(), env.clone(), user_info.clone(), mint(1)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MintRateExceeded {
+            remaining: Uint128::zero()
+        }
+    );
+    let capacity =
+        query_mint_capacity(deps.as_ref(), env.clone(), Some(USER1.to_string())).unwrap();
+    assert_eq!(capacity.global.unwrap().remaining, Uint128::new(70));
+    assert_eq!(capacity.minter.unwrap().remaining, Uint128::zero());
+    assert_eq!(capacity.remaining, Some(Uint128::zero()));
+
+    // 시간 기반 에포크가 지나면 minter 한도 초기화
+    env.block.time = env.block.time.plus_seconds(60);
+    execute(deps.as_mut(), env.clone(), user_info, mint(30)).unwrap();
+
+    // 한도 해제
+    execute(deps.as_mut(), env.clone(), admin_info, set_limit(None, None)).unwrap();
+    let capacity = query_mint_capacity(deps.as_ref(), env.clone(), None).unwrap();
+    assert_eq!(capacity.global, None);
+    assert_eq!(capacity.remaining, None);
+    execute(deps.as_mut(), env, creator_info, mint(500)).unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), recipient.to_string()).unwrap().balance,
+        Uint128::new(640)
+    );
+}
+
+#[test]
+fn test_batch_mint() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let alice = deps.api.addr_make("alice");
+    let bob = deps.api.addr_make("bob");
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(100),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: Some(MinterResponse {
+            minter: CREATOR.to_string(),
+            cap: Some(Uint128::new(1000)),
+        }),
+        created_on_platform: None,
+    };
+    let creator_info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), creator_info.clone(), msg).unwrap();
+
+    let batch = |mints: &[(&Addr, u128)]| ExecuteMsg::BatchMint {
+        mints: mints
+            .iter()
+            .map(|(address, amount)| Cw20Coin {
+                address: address.to_string(),
+                amount: Uint128::new(*amount),
+            })
+            .collect(),
+    };
+
+    let err = execute(deps.as_mut(), env.clone(), creator_info.clone(), batch(&[])).unwrap_err();
+    assert_eq!(err, ContractError::EmptyBatch {});
+
+    // minter가 아니면 거부
+    let user_info = MessageInfo {
+        sender: Addr::unchecked(USER1),
+        funds: vec![],
+    };
+    let err =
+        execute(deps.as_mut(), env.clone(), user_info, batch(&[(&alice, 10)])).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // cap은 총액 기준으로 확인
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        creator_info.clone(),
+        batch(&[(&alice, 500), (&bob, 401)]),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::CannotExceedCap {});
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        creator_info,
+        batch(&[(&alice, 100), (&bob, 200), (&alice, 50)]),
+    )
+    .unwrap();
+    assert_eq!(res.attributes[0].value, "batch_mint");
+    let pairs: Vec<_> = res
+        .attributes
+        .iter()
+        .filter(|attr| attr.key == "to" || attr.key == "amount")
+        .map(|attr| attr.value.clone())
+        .collect();
+    assert_eq!(
+        pairs,
+        vec![
+            alice.to_string(),
+            "100".to_string(),
+            bob.to_string(),
+            "200".to_string(),
+            alice.to_string(),
+            "50".to_string(),
+        ]
+    );
+
+    assert_eq!(
+        query_balance(deps.as_ref(), alice.to_string()).unwrap().balance,
+        Uint128::new(150)
+    );
+    assert_eq!(
+        query_balance(deps.as_ref(), bob.to_string()).unwrap().balance,
+        Uint128::new(200)
+    );
+    assert_eq!(
+        TOKEN_INFO.load(deps.as_ref().storage).unwrap().total_supply,
+        Uint128::new(450)
+    );
+}
+
+#[test]
+fn test_mint_supply_overflow() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let alice = deps.api.addr_make("alice");
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(100),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: Some(MinterResponse {
+            minter: CREATOR.to_string(),
+            cap: None,
+        }),
+        created_on_platform: None,
+    };
+    let creator_info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), creator_info.clone(), msg).unwrap();
+
+    // cap이 없어도 총 공급량이 넘치면 panic 대신 오류
+    let msg = ExecuteMsg::Mint {
+        recipient: alice.to_string(),
+        amount: Uint128::MAX,
+    };
+    let err = execute(deps.as_mut(), env.clone(), creator_info.clone(), msg).unwrap_err();
+    assert_eq!(err, ContractError::InvalidAmount {});
+
+    let msg = ExecuteMsg::BatchMint {
+        mints: vec![Cw20Coin {
+            address: alice.to_string(),
+            amount: Uint128::MAX,
+        }],
+    };
+    let err = execute(deps.as_mut(), env, creator_info, msg).unwrap_err();
+    assert_eq!(err, ContractError::InvalidAmount {});
+    assert_eq!(
+        TOKEN_INFO.load(deps.as_ref().storage).unwrap().total_supply,
+        Uint128::new(100)
+    );
+}
+
+#[test]
+fn test_two_step_minter_handover() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let minter = deps.api.addr_make("minter");
+    let new_minter = deps.api.addr_make("new_minter");
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![],
+        marketing: None,
+        mint: Some(MinterResponse {
+            minter: minter.to_string(),
+            cap: Some(Uint128::new(1000)),
+        }),
+        created_on_platform: None,
+    };
+    let minter_info = MessageInfo {
+        sender: minter.clone(),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), minter_info.clone(), msg).unwrap();
+
+    // force 없이 즉시 교체 불가
+    let msg = ExecuteMsg::UpdateMinter {
+        new_minter: Some(new_minter.to_string()),
+        force: false,
+    };
+    let err = execute(deps.as_mut(), env.clone(), minter_info.clone(), msg).unwrap_err();
+    assert_eq!(err, ContractError::ForceRequired {});
+
+    // 현재 minter만 제안 가능
+    let propose = ExecuteMsg::ProposeMinter {
+        new_minter: new_minter.to_string(),
+        expiry: Some(Expiration::AtHeight(env.block.height + 10)),
+    };
+    let new_minter_info = MessageInfo {
+        sender: new_minter.clone(),
+        funds: vec![],
+    };
+    let err =
+        execute(deps.as_mut(), env.clone(), new_minter_info.clone(), propose.clone()).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), minter_info.clone(), propose.clone()).unwrap();
+    let pending = query_pending_minter(deps.as_ref()).unwrap().unwrap();
+    assert_eq!(pending.new_minter, new_minter.to_string());
+    assert_eq!(pending.expires, Expiration::AtHeight(env.block.height + 10));
+
+    // 취소 후에는 수락 불가
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        minter_info.clone(),
+        ExecuteMsg::CancelMinterProposal {},
+    )
+    .unwrap();
+    assert_eq!(query_pending_minter(deps.as_ref()).unwrap(), None);
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        new_minter_info.clone(),
+        ExecuteMsg::AcceptMinter {},
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NoPendingMinter {});
+
+    // 제안받은 주소만, 만료 전에만 수락 가능
+    execute(deps.as_mut(), env.clone(), minter_info.clone(), propose).unwrap();
+    let err = execute(deps.as_mut(), env.clone(), minter_info.clone(), ExecuteMsg::AcceptMinter {})
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let mut expired_env = env.clone();
+    expired_env.block.height += 10;
+    let err = execute(
+        deps.as_mut(),
+        expired_env,
+        new_minter_info.clone(),
+        ExecuteMsg::AcceptMinter {},
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::MinterProposalExpired {});
+
+    env.block.height += 5;
+    execute(deps.as_mut(), env.clone(), new_minter_info.clone(), ExecuteMsg::AcceptMinter {})
+        .unwrap();
+    let current = query_minter(deps.as_ref()).unwrap().unwrap();
+    assert_eq!(current.minter, new_minter.to_string());
+    assert_eq!(current.cap, Some(Uint128::new(1000)));
+    assert_eq!(query_pending_minter(deps.as_ref()).unwrap(), None);
+
+    // 이전 minter는 더 이상 발행 불가
+    let mint = ExecuteMsg::Mint {
+        recipient: minter.to_string(),
+        amount: Uint128::new(10),
+    };
+    let err = execute(deps.as_mut(), env.clone(), minter_info, mint.clone()).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), new_minter_info.clone(), mint).unwrap();
+
+    // force 지정 시 즉시 교체
+    let msg = ExecuteMsg::UpdateMinter {
+        new_minter: None,
+        force: true,
+    };
+    execute(deps.as_mut(), env, new_minter_info, msg).unwrap();
+    assert_eq!(query_minter(deps.as_ref()).unwrap(), None);
+}
+
+#[test]
+fn test_burn_with_reason() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let gamer = deps.api.addr_make("gamer");
+    let bridger = deps.api.addr_make("bridger");
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![
+            InitialBalance {
+                address: gamer.to_string(),
+                amount: Uint128::new(1000),
+                vesting: None,
+            },
+            InitialBalance {
+                address: bridger.to_string(),
+                amount: Uint128::new(1000),
+                vesting: None,
+            },
+        ],
+        marketing: None,
+        mint: None,
+        created_on_platform: None,
+    };
+    let info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let gamer_info = MessageInfo {
+        sender: gamer.clone(),
+        funds: vec![],
+    };
+    let burn = |amount: u128, reason: &str, reference: Option<&str>| ExecuteMsg::BurnWithReason {
+        amount: Uint128::new(amount),
+        reason: reason.to_string(),
+        reference: reference.map(str::to_string),
+    };
+
+    // 사유는 필수
+    let err =
+        execute(deps.as_mut(), env.clone(), gamer_info.clone(), burn(10, " ", None)).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidBurnReason { .. }));
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        gamer_info.clone(),
+        burn(100, "item", Some("sword#1")),
+    )
+    .unwrap();
+    assert_eq!(res.attributes[0].value, "burn");
+    assert!(res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "receipt_id" && attr.value == "1"));
+
+    env.block.height += 1;
+    let bridger_info = MessageInfo {
+        sender: bridger.clone(),
+        funds: vec![],
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        bridger_info,
+        burn(300, "bridge", Some("dest:0xabc")),
+    )
+    .unwrap();
+
+    env.block.height += 1;
+    execute(deps.as_mut(), env.clone(), gamer_info.clone(), burn(50, "item", None)).unwrap();
+
+    // 잔액 부족 시 영수증 없음
+    let err = execute(deps.as_mut(), env.clone(), gamer_info, burn(5000, "item", None));
+    assert!(err.is_err());
+
+    let receipt = query_burn_receipt(deps.as_ref(), 2).unwrap();
+    assert_eq!(receipt.burner, bridger.to_string());
+    assert_eq!(receipt.amount, Uint128::new(300));
+    assert_eq!(receipt.reason, "bridge");
+    assert_eq!(receipt.reference, Some("dest:0xabc".to_string()));
+    assert_eq!(receipt.height, env.block.height - 1);
+    assert_eq!(receipt.time, env.block.time);
+    assert!(query_burn_receipt(deps.as_ref(), 4).is_err());
+
+    let receipts =
+        query_burn_receipts_by_burner(deps.as_ref(), gamer.to_string(), None, None).unwrap();
+    let ids: Vec<u64> = receipts.receipts.iter().map(|r| r.id).collect();
+    assert_eq!(ids, vec![1, 3]);
+    let receipts =
+        query_burn_receipts_by_burner(deps.as_ref(), gamer.to_string(), Some(1), Some(1)).unwrap();
+    assert_eq!(receipts.receipts.len(), 1);
+    assert_eq!(receipts.receipts[0].id, 3);
+
+    assert_eq!(
+        query_balance(deps.as_ref(), gamer.to_string()).unwrap().balance,
+        Uint128::new(850)
+    );
+    assert_eq!(
+        TOKEN_INFO.load(deps.as_ref().storage).unwrap().total_supply,
+        Uint128::new(1550)
+    );
+}
+
+#[test]
+fn test_total_supply_snapshots() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let start = env.block.height;
+    let holder = deps.api.addr_make("holder");
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(1000),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: Some(MinterResponse {
+            minter: CREATOR.to_string(),
+            cap: None,
+        }),
+        created_on_platform: None,
+    };
+    let creator_info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), creator_info.clone(), msg).unwrap();
+
+    // 같은 블록에서 여러 번 바뀌어도 블록 단위로 기록
+    env.block.height += 1;
+    for _ in 0..2 {
+        let msg = ExecuteMsg::Mint {
+            recipient: holder.to_string(),
+            amount: Uint128::new(250),
+        };
+        execute(deps.as_mut(), env.clone(), creator_info.clone(), msg).unwrap();
+    }
+
+    env.block.height += 1;
+    let holder_info = MessageInfo {
+        sender: holder.clone(),
+        funds: vec![],
+    };
+    let msg = ExecuteMsg::Burn {
+        amount: Uint128::new(200),
+    };
+    execute(deps.as_mut(), env.clone(), holder_info, msg).unwrap();
+
+    let supply_at = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, height: u64| {
+        query_total_supply_at_height(deps.as_ref(), height)
+            .unwrap()
+            .total_supply
+    };
+    // height 블록 시작 시점 기준
+    assert_eq!(supply_at(&deps, start), None);
+    assert_eq!(supply_at(&deps, start + 1), Some(Uint128::new(1000)));
+    assert_eq!(supply_at(&deps, start + 2), Some(Uint128::new(1500)));
+    assert_eq!(supply_at(&deps, start + 3), Some(Uint128::new(1300)));
+    assert_eq!(supply_at(&deps, start + 100), Some(Uint128::new(1300)));
+
+    // 기록이 이미 있으면 마이그레이션에서 다시 기록하지 않음
+    let res = migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "total_supply_history_seeded" && attr.value == "false"));
+
+    // 기록이 없던 기존 컨트랙트는 현재 총 발행량부터 기록
+    let mut legacy = mock_dependencies();
+    let token_info = TOKEN_INFO.load(deps.as_ref().storage).unwrap();
+    TOKEN_INFO.save(legacy.as_mut().storage, &token_info).unwrap();
+    assert!(seed_total_supply_history(legacy.as_mut().storage, start + 10).unwrap());
+    assert_eq!(supply_at(&legacy, start + 10), None);
+    assert_eq!(supply_at(&legacy, start + 11), Some(Uint128::new(1300)));
+    assert!(!seed_total_supply_history(legacy.as_mut().storage, start + 11).unwrap());
+}
+
+#[test]
+fn test_update_config_cannot_replace_minter() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let minter = deps.api.addr_make("minter");
+    let proposed = deps.api.addr_make("proposed");
+    let replacement = deps.api.addr_make("replacement");
+
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(100),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: Some(MinterResponse {
+            minter: minter.to_string(),
+            cap: None,
+        }),
+        created_on_platform: None,
+    };
+    let minter_info = MessageInfo {
+        sender: minter.clone(),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), minter_info.clone(), msg).unwrap();
+
+    let msg = ExecuteMsg::ProposeMinter {
+        new_minter: proposed.to_string(),
+        expiry: None,
+    };
+    execute(deps.as_mut(), env.clone(), minter_info.clone(), msg).unwrap();
+
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+    let update_config = |minter: Option<&Addr>| ExecuteMsg::UpdateConfig {
+        new_config: Box::new(Some(ConfigInfo {
+            is_upgrade_allowed: true,
+            upgrade_admin: None,
+            marketing: None,
+            minter: minter.map(|minter| MinterResponse {
+                minter: minter.to_string(),
+                cap: None,
+            }),
+            transfer_fee: None,
+            fee_collector: None,
+            max_supply: None,
+        })),
+    };
+
+    // UpdateConfig로는 minter를 교체하거나 제거할 수 없음
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), update_config(Some(&replacement)))
+        .unwrap_err();
+    assert_eq!(err, ContractError::ForceRequired {});
+    let err = execute(deps.as_mut(), env.clone(), admin_info.clone(), update_config(None))
+        .unwrap_err();
+    assert_eq!(err, ContractError::ForceRequired {});
+    execute(deps.as_mut(), env.clone(), admin_info, update_config(Some(&minter))).unwrap();
+    assert!(query_pending_minter(deps.as_ref()).unwrap().is_some());
+
+    // 즉시 교체하면 이전 제안은 무효
+    let msg = ExecuteMsg::UpdateMinter {
+        new_minter: Some(replacement.to_string()),
+        force: true,
+    };
+    execute(deps.as_mut(), env.clone(), minter_info, msg).unwrap();
+    assert_eq!(query_pending_minter(deps.as_ref()).unwrap(), None);
+    assert_eq!(
+        CONFIG.load(deps.as_ref().storage).unwrap().minter.unwrap().minter,
+        replacement.to_string()
+    );
+    let proposed_info = MessageInfo {
+        sender: proposed,
+        funds: vec![],
+    };
+    let err = execute(deps.as_mut(), env, proposed_info, ExecuteMsg::AcceptMinter {}).unwrap_err();
+    assert_eq!(err, ContractError::NoPendingMinter {});
+    assert_eq!(
+        query_minter(deps.as_ref()).unwrap().unwrap().minter,
+        replacement.to_string()
+    );
+}
@@ -0,0 +1,270 @@
+use cosmwasm_std::testing::{mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{MessageInfo, OwnedDeps, Uint128};
+use cw_utils::Duration;
+
+use crate::contract::{execute, query_balance};
+use crate::error::ContractError;
+use crate::fee::{FeeTokenType, FeeType};
+use crate::msg::{ExecuteMsg, FeeCollectorInput};
+use crate::reversible::{query_pending_transfer, query_pending_transfers};
+use crate::tests::helpers::{self, Accounts};
+
+fn setup() -> (OwnedDeps<MockStorage, MockApi, MockQuerier>, Accounts) {
+    let (mut deps, accounts) = helpers::setup();
+
+    // sender가 관리자. 되돌림 기간 10블록
+    let info = MessageInfo {
+        sender: accounts.sender.clone(),
+        funds: vec![],
+    };
+    let msg = ExecuteMsg::SetReversibleWindow {
+        window: Duration::Height(10),
+    };
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    (deps, accounts)
+}
+
+#[test]
+fn test_reverse_within_window() {
+    let (mut deps, accounts) = setup();
+    let env = mock_env();
+    let sender_info = MessageInfo {
+        sender: accounts.sender.clone(),
+        funds: vec![],
+    };
+    let recipient_info = MessageInfo {
+        sender: accounts.recipient.clone(),
+        funds: vec![],
+    };
+
+    let msg = ExecuteMsg::ReversibleTransfer {
+        recipient: accounts.recipient.to_string(),
+        amount: Uint128::new(100000),
+    };
+    execute(deps.as_mut(), env.clone(), sender_info.clone(), msg).unwrap();
+
+    // 대기 중인 금액은 recipient 잔액에 포함되지 않음
+    assert_eq!(
+        query_balance(deps.as_ref(), accounts.sender.to_string()).unwrap().balance,
+        Uint128::new(900000)
+    );
+    assert_eq!(
+        query_balance(deps.as_ref(), accounts.recipient.to_string()).unwrap().balance,
+        Uint128::zero()
+    );
+    let pending =
+        query_pending_transfers(deps.as_ref(), accounts.recipient.to_string(), None, None)
+            .unwrap();
+    assert_eq!(pending.transfers.len(), 1);
+    assert_eq!(pending.transfers[0].amount, Uint128::new(100000));
+
+    // 기간 내에는 수령 불가, sender 외에는 되돌릴 수 없음
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        recipient_info.clone(),
+        ExecuteMsg::AcceptTransfer { id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ReversalWindowOpen {});
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        recipient_info,
+        ExecuteMsg::ReverseTransfer { id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    execute(deps.as_mut(), env, sender_info, ExecuteMsg::ReverseTransfer { id: 1 }).unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), accounts.sender.to_string()).unwrap().balance,
+        Uint128::new(1000000)
+    );
+    assert!(query_pending_transfer(deps.as_ref(), 1).is_err());
+}
+
+#[test]
+fn test_accept_and_auto_settle() {
+    let (mut deps, accounts) = setup();
+    let mut env = mock_env();
+    let sender_info = MessageInfo {
+        sender: accounts.sender.clone(),
+        funds: vec![],
+    };
+    let recipient_info = MessageInfo {
+        sender: accounts.recipient.clone(),
+        funds: vec![],
+    };
+
+    let msg = ExecuteMsg::ReversibleTransfer {
+        recipient: accounts.recipient.to_string(),
+        amount: Uint128::new(100000),
+    };
+    execute(deps.as_mut(), env.clone(), sender_info.clone(), msg.clone()).unwrap();
+    execute(deps.as_mut(), env.clone(), sender_info.clone(), msg).unwrap();
+
+    // 기간이 지나면 되돌릴 수 없음
+    env.block.height += 10;
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        sender_info,
+        ExecuteMsg::ReverseTransfer { id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ReversalWindowClosed {});
+
+    // 직접 수령
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        recipient_info.clone(),
+        ExecuteMsg::AcceptTransfer { id: 1 },
+    )
+    .unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), accounts.recipient.to_string()).unwrap().balance,
+        Uint128::new(100000)
+    );
+
+    // 나머지 건은 recipient의 다음 실행에서 자동 확정되어 바로 사용 가능
+    let msg = ExecuteMsg::Transfer {
+        recipient: accounts.other.to_string(),
+        amount: Uint128::new(150000),
+    };
+    let res = execute(deps.as_mut(), env, recipient_info, msg).unwrap();
+    let settled = res.events.iter().find(|e| e.ty == "settle_transfer").unwrap();
+    assert!(settled.attributes.iter().any(|attr| attr.key == "id" && attr.value == "2"));
+    assert_eq!(
+        query_balance(deps.as_ref(), accounts.recipient.to_string()).unwrap().balance,
+        Uint128::new(50000)
+    );
+    assert!(query_pending_transfers(deps.as_ref(), accounts.recipient.to_string(), None, None)
+        .unwrap()
+        .transfers
+        .is_empty());
+}
+
+#[test]
+fn test_auto_settle_skips_failing_entries() {
+    let (mut deps, accounts) = setup();
+    let mut env = mock_env();
+    let sender_info = MessageInfo {
+        sender: accounts.sender.clone(),
+        funds: vec![],
+    };
+    let recipient_info = MessageInfo {
+        sender: accounts.recipient.clone(),
+        funds: vec![],
+    };
+
+    let msg = ExecuteMsg::SetTransferLimits {
+        max_tx_amount: None,
+        max_wallet_balance: Some(Uint128::new(1000)),
+        exempt: vec![],
+    };
+    execute(deps.as_mut(), env.clone(), sender_info.clone(), msg).unwrap();
+    let msg = ExecuteMsg::Transfer {
+        recipient: accounts.recipient.to_string(),
+        amount: Uint128::new(10),
+    };
+    execute(deps.as_mut(), env.clone(), sender_info.clone(), msg).unwrap();
+
+    // 지갑 한도 때문에 확정할 수 없는 10건 뒤에 확정 가능한 1건
+    for amount in [2000u128; 10].into_iter().chain([500]) {
+        let msg = ExecuteMsg::ReversibleTransfer {
+            recipient: accounts.recipient.to_string(),
+            amount: Uint128::new(amount),
+        };
+        execute(deps.as_mut(), env.clone(), sender_info.clone(), msg).unwrap();
+    }
+    env.block.height += 10;
+
+    // 전송 계열이 아닌 메시지에서는 확정하지 않음
+    let msg = ExecuteMsg::IncreaseAllowance {
+        spender: accounts.other.to_string(),
+        amount: Uint128::new(1),
+        expires: None,
+        allowed_recipients: None,
+    };
+    let res = execute(deps.as_mut(), env.clone(), recipient_info.clone(), msg).unwrap();
+    assert!(res.events.is_empty());
+
+    let transfer = ExecuteMsg::Transfer {
+        recipient: accounts.other.to_string(),
+        amount: Uint128::new(1),
+    };
+    let res = execute(deps.as_mut(), env.clone(), recipient_info.clone(), transfer.clone()).unwrap();
+    assert!(res.events.is_empty());
+
+    // 실패한 건에 막히지 않고 다음 실행에서 이어서 확정
+    let res = execute(deps.as_mut(), env.clone(), recipient_info, transfer).unwrap();
+    let settled = res.events.iter().find(|e| e.ty == "settle_transfer").unwrap();
+    assert!(settled.attributes.iter().any(|attr| attr.key == "id" && attr.value == "11"));
+    assert_eq!(
+        query_balance(deps.as_ref(), accounts.recipient.to_string()).unwrap().balance,
+        Uint128::new(508)
+    );
+    assert_eq!(
+        query_pending_transfers(deps.as_ref(), accounts.recipient.to_string(), None, Some(30))
+            .unwrap()
+            .transfers
+            .len(),
+        10
+    );
+}
+
+#[test]
+fn test_settle_with_fixed_fee_above_amount() {
+    let (mut deps, accounts) = setup();
+    let mut env = mock_env();
+    let sender_info = MessageInfo {
+        sender: accounts.sender.clone(),
+        funds: vec![],
+    };
+    let recipient_info = MessageInfo {
+        sender: accounts.recipient.clone(),
+        funds: vec![],
+    };
+
+    let msg = ExecuteMsg::ReversibleTransfer {
+        recipient: accounts.recipient.to_string(),
+        amount: Uint128::new(100),
+    };
+    execute(deps.as_mut(), env.clone(), sender_info.clone(), msg).unwrap();
+
+    // 전송 후 전송액보다 큰 고정 수수료 설정
+    let msg = ExecuteMsg::SetFeeConfig {
+        fee_type: FeeType::Fixed(Uint128::new(1000)),
+        token_type: FeeTokenType::Cw20 {
+            contract_addr: "self".to_string(),
+        },
+        collectors: vec![FeeCollectorInput {
+            address: accounts.other.to_string(),
+            percentage: "1.0".to_string(),
+        }],
+        is_active: true,
+        batch_fee_mode: None,
+    };
+    execute(deps.as_mut(), env.clone(), sender_info, msg).unwrap();
+
+    // 수수료는 전송액까지만 부과되어 기간이 지난 뒤에도 수령 가능
+    env.block.height += 10;
+    execute(
+        deps.as_mut(),
+        env,
+        recipient_info,
+        ExecuteMsg::AcceptTransfer { id: 1 },
+    )
+    .unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), accounts.other.to_string()).unwrap().balance,
+        Uint128::new(100)
+    );
+    assert_eq!(
+        query_balance(deps.as_ref(), accounts.recipient.to_string()).unwrap().balance,
+        Uint128::zero()
+    );
+    assert!(query_pending_transfer(deps.as_ref(), 1).is_err());
+}
@@ -0,0 +1,231 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{Addr, Decimal, MessageInfo, OwnedDeps, Uint128};
+use cw20::Expiration;
+
+use crate::contract::{execute, instantiate, query_balance};
+use crate::error::ContractError;
+use crate::fee::{FeeTokenType, FeeType};
+use crate::msg::{ExecuteMsg, FeeCollectorInput, InitialBalance, InstantiateMsg};
+use crate::scheduled::query_scheduled_transfer;
+
+// 테스트 상수 정의
+const CREATOR: &str = "cosmos1vlhe6z8r7al2lyzp7n3j2vl5kd28hhrw0vxmxr";
+const ADMIN: &str = "cosmos1wztmxhufhy98p3n45yqtwhrxlrr9wkg0tt3a3c";
+const PARTNER: &str = "cosmos1qg9zllptnqvhyvrrvm0j3qjmtc5q6ds7eq0le4";
+const FEE_COLLECTOR: &str = "cosmos1fn9z9vn4k3qwr7vkg0yhzwv2q8h4lu4qsh7qv3";
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies();
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![InitialBalance {
+            address: ADMIN.to_string(),
+            amount: Uint128::new(1000000),
+            vesting: None,
+        }],
+        marketing: None,
+        mint: None,
+        created_on_platform: None,
+    };
+    let info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+    deps
+}
+
+#[test]
+fn test_scheduled_transfer_release() {
+    let mut deps = setup();
+    let mut env = mock_env();
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+
+    // 1% 수수료 설정
+    let set_fee_msg = ExecuteMsg::SetFeeConfig {
+        fee_type: FeeType::Percentage(Decimal::percent(1)),
+        token_type: FeeTokenType::Cw20 {
+            contract_addr: "self".to_string(),
+        },
+        collectors: vec![FeeCollectorInput {
+            address: FEE_COLLECTOR.to_string(),
+            percentage: "1.0".to_string(),
+        }],
+        is_active: true,
+        batch_fee_mode: None,
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), set_fee_msg).unwrap();
+
+    // 10블록 뒤 지급 예약
+    let msg = ExecuteMsg::ScheduleTransfer {
+        recipient: PARTNER.to_string(),
+        amount: Uint128::new(100000),
+        release_at: Expiration::AtHeight(env.block.height + 10),
+        cancellable_until: None,
+    };
+    let res = execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+    let id_attr = res.attributes.iter().find(|attr| attr.key == "id").unwrap();
+    assert_eq!(id_attr.value, "1");
+
+    // 에스크로로 이동 확인
+    assert_eq!(
+        query_balance(deps.as_ref(), ADMIN.to_string()).unwrap().balance,
+        Uint128::new(900000)
+    );
+    let scheduled = query_scheduled_transfer(deps.as_ref(), 1).unwrap();
+    assert_eq!(scheduled.recipient, PARTNER);
+    assert_eq!(scheduled.amount, Uint128::new(100000));
+
+    // 취소 불가 예약
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info,
+        ExecuteMsg::CancelScheduled { id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotCancellable {});
+
+    // 아직 지급 불가
+    let anyone = MessageInfo {
+        sender: Addr::unchecked(FEE_COLLECTOR),
+        funds: vec![],
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        anyone.clone(),
+        ExecuteMsg::ReleaseScheduled { id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotYetReleasable {});
+
+    // 시점 도래 후 누구나 지급 실행 (수수료 적용)
+    env.block.height += 10;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        anyone.clone(),
+        ExecuteMsg::ReleaseScheduled { id: 1 },
+    )
+    .unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), PARTNER.to_string()).unwrap().balance,
+        Uint128::new(99000)
+    );
+    assert_eq!(
+        query_balance(deps.as_ref(), FEE_COLLECTOR.to_string()).unwrap().balance,
+        Uint128::new(1000)
+    );
+    assert!(query_scheduled_transfer(deps.as_ref(), 1).is_err());
+
+    // 중복 지급 불가
+    execute(deps.as_mut(), env, anyone, ExecuteMsg::ReleaseScheduled { id: 1 }).unwrap_err();
+}
+
+#[test]
+fn test_scheduled_transfer_cancel() {
+    let mut deps = setup();
+    let mut env = mock_env();
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+
+    let msg = ExecuteMsg::ScheduleTransfer {
+        recipient: PARTNER.to_string(),
+        amount: Uint128::new(100000),
+        release_at: Expiration::AtHeight(env.block.height + 10),
+        cancellable_until: Some(Expiration::AtHeight(env.block.height + 5)),
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg.clone()).unwrap();
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+
+    // sender만 취소 가능
+    let partner_info = MessageInfo {
+        sender: Addr::unchecked(PARTNER),
+        funds: vec![],
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        partner_info,
+        ExecuteMsg::CancelScheduled { id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // 취소 기한 내 취소 시 전액 환불
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        ExecuteMsg::CancelScheduled { id: 1 },
+    )
+    .unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), ADMIN.to_string()).unwrap().balance,
+        Uint128::new(900000)
+    );
+
+    // 취소 기한이 지나면 취소 불가
+    env.block.height += 5;
+    let err = execute(
+        deps.as_mut(),
+        env,
+        admin_info,
+        ExecuteMsg::CancelScheduled { id: 2 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotCancellable {});
+}
+
+#[test]
+fn test_release_with_fixed_fee_above_amount() {
+    let mut deps = setup();
+    let mut env = mock_env();
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+
+    // 취소 불가 예약 후 예약액보다 큰 고정 수수료 설정
+    let msg = ExecuteMsg::ScheduleTransfer {
+        recipient: PARTNER.to_string(),
+        amount: Uint128::new(100),
+        release_at: Expiration::AtHeight(env.block.height + 10),
+        cancellable_until: None,
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), msg).unwrap();
+    let set_fee_msg = ExecuteMsg::SetFeeConfig {
+        fee_type: FeeType::Fixed(Uint128::new(1000)),
+        token_type: FeeTokenType::Cw20 {
+            contract_addr: "self".to_string(),
+        },
+        collectors: vec![FeeCollectorInput {
+            address: FEE_COLLECTOR.to_string(),
+            percentage: "1.0".to_string(),
+        }],
+        is_active: true,
+        batch_fee_mode: None,
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), set_fee_msg).unwrap();
+
+    // 수수료는 예약액까지만 부과되어 지급이 막히지 않음
+    env.block.height += 10;
+    execute(deps.as_mut(), env, admin_info, ExecuteMsg::ReleaseScheduled { id: 1 }).unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), FEE_COLLECTOR.to_string()).unwrap().balance,
+        Uint128::new(100)
+    );
+    assert_eq!(
+        query_balance(deps.as_ref(), PARTNER.to_string()).unwrap().balance,
+        Uint128::zero()
+    );
+    assert!(query_scheduled_transfer(deps.as_ref(), 1).is_err());
+}
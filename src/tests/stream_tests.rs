@@ -0,0 +1,204 @@
+use cosmwasm_std::testing::{mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{Addr, MessageInfo, OwnedDeps, Uint128};
+
+use crate::contract::{execute, query_balance};
+use crate::error::ContractError;
+use crate::fee::{FeeTokenType, FeeType};
+use crate::msg::{ExecuteMsg, FeeCollectorInput};
+use crate::stream::{query_stream, query_streams_by_recipient, query_streams_by_sender};
+use crate::tests::helpers::setup;
+
+fn balance(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, addr: &Addr) -> Uint128 {
+    query_balance(deps.as_ref(), addr.to_string()).unwrap().balance
+}
+
+#[test]
+fn test_stream_withdraw() {
+    let (mut deps, accounts) = setup();
+    let mut env = mock_env();
+    let start = env.block.height + 10;
+    let sender_info = MessageInfo {
+        sender: accounts.sender.clone(),
+        funds: vec![],
+    };
+    let recipient_info = MessageInfo {
+        sender: accounts.recipient.clone(),
+        funds: vec![],
+    };
+
+    // 종료 높이가 시작보다 앞서면 거부
+    let msg = ExecuteMsg::CreateStream {
+        recipient: accounts.recipient.to_string(),
+        total: Uint128::new(100000),
+        start,
+        end: start,
+    };
+    let err = execute(deps.as_mut(), env.clone(), sender_info.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidStream { .. }));
+
+    let msg = ExecuteMsg::CreateStream {
+        recipient: accounts.recipient.to_string(),
+        total: Uint128::new(100000),
+        start,
+        end: start + 100,
+    };
+    execute(deps.as_mut(), env.clone(), sender_info, msg).unwrap();
+    assert_eq!(balance(&deps, &accounts.sender), Uint128::new(900000));
+
+    // 시작 전에는 인출할 양이 없음
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        recipient_info.clone(),
+        ExecuteMsg::WithdrawStream { id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NothingToWithdraw {});
+
+    // 25% 경과 시점 인출
+    env.block.height = start + 25;
+    let stream = query_stream(deps.as_ref(), env.clone(), 1).unwrap();
+    assert_eq!(stream.withdrawable, Uint128::new(25000));
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        MessageInfo {
+            sender: accounts.other.clone(),
+            funds: vec![],
+        },
+        ExecuteMsg::WithdrawStream { id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        recipient_info.clone(),
+        ExecuteMsg::WithdrawStream { id: 1 },
+    )
+    .unwrap();
+    assert_eq!(balance(&deps, &accounts.recipient), Uint128::new(25000));
+    let stream = query_stream(deps.as_ref(), env.clone(), 1).unwrap();
+    assert_eq!(stream.withdrawn, Uint128::new(25000));
+    assert_eq!(stream.withdrawable, Uint128::zero());
+
+    // 종료 후 나머지 전액 인출 시 스트림 삭제
+    env.block.height = start + 200;
+    execute(deps.as_mut(), env.clone(), recipient_info, ExecuteMsg::WithdrawStream { id: 1 })
+        .unwrap();
+    assert_eq!(balance(&deps, &accounts.recipient), Uint128::new(100000));
+    assert!(query_stream(deps.as_ref(), env, 1).is_err());
+}
+
+#[test]
+fn test_stream_cancel() {
+    let (mut deps, accounts) = setup();
+    let mut env = mock_env();
+    let start = env.block.height;
+    let sender_info = MessageInfo {
+        sender: accounts.sender.clone(),
+        funds: vec![],
+    };
+
+    for recipient in [&accounts.recipient, &accounts.other] {
+        let msg = ExecuteMsg::CreateStream {
+            recipient: recipient.to_string(),
+            total: Uint128::new(100000),
+            start,
+            end: start + 100,
+        };
+        execute(deps.as_mut(), env.clone(), sender_info.clone(), msg).unwrap();
+    }
+
+    let by_sender =
+        query_streams_by_sender(deps.as_ref(), env.clone(), accounts.sender.to_string(), None, None)
+            .unwrap();
+    assert_eq!(by_sender.streams.len(), 2);
+    let by_recipient = query_streams_by_recipient(
+        deps.as_ref(),
+        env.clone(),
+        accounts.recipient.to_string(),
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(by_recipient.streams.len(), 1);
+    assert_eq!(by_recipient.streams[0].id, 1);
+
+    // recipient는 취소할 수 없음
+    env.block.height = start + 40;
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        MessageInfo {
+            sender: accounts.recipient.clone(),
+            funds: vec![],
+        },
+        ExecuteMsg::CancelStream { id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // 쌓인 40%는 recipient에게, 나머지 60%는 sender에게
+    execute(deps.as_mut(), env.clone(), sender_info, ExecuteMsg::CancelStream { id: 1 })
+        .unwrap();
+    assert_eq!(balance(&deps, &accounts.recipient), Uint128::new(40000));
+    assert_eq!(balance(&deps, &accounts.sender), Uint128::new(860000));
+    assert!(query_stream(deps.as_ref(), env, 1).is_err());
+}
+
+#[test]
+fn test_stream_with_fixed_fee_above_chunk() {
+    let (mut deps, accounts) = setup();
+    let mut env = mock_env();
+    let sender_info = MessageInfo {
+        sender: accounts.sender.clone(),
+        funds: vec![],
+    };
+    let recipient_info = MessageInfo {
+        sender: accounts.recipient.clone(),
+        funds: vec![],
+    };
+
+    let msg = ExecuteMsg::CreateStream {
+        recipient: accounts.recipient.to_string(),
+        total: Uint128::new(100),
+        start: env.block.height,
+        end: env.block.height + 100,
+    };
+    execute(deps.as_mut(), env.clone(), sender_info.clone(), msg).unwrap();
+
+    // 인출 단위보다 큰 고정 수수료 설정
+    let msg = ExecuteMsg::SetFeeConfig {
+        fee_type: FeeType::Fixed(Uint128::new(50)),
+        token_type: FeeTokenType::Cw20 {
+            contract_addr: "self".to_string(),
+        },
+        collectors: vec![FeeCollectorInput {
+            address: accounts.other.to_string(),
+            percentage: "1.0".to_string(),
+        }],
+        is_active: true,
+        batch_fee_mode: None,
+    };
+    execute(deps.as_mut(), env.clone(), sender_info.clone(), msg).unwrap();
+
+    // 수수료는 인출액까지만 부과
+    env.block.height += 10;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        recipient_info,
+        ExecuteMsg::WithdrawStream { id: 1 },
+    )
+    .unwrap();
+    assert_eq!(balance(&deps, &accounts.other), Uint128::new(10));
+    assert_eq!(balance(&deps, &accounts.recipient), Uint128::zero());
+
+    // 취소 시 쌓인 양 지급도 막히지 않고 나머지는 반환
+    env.block.height += 10;
+    execute(deps.as_mut(), env, sender_info, ExecuteMsg::CancelStream { id: 1 }).unwrap();
+    assert_eq!(balance(&deps, &accounts.other), Uint128::new(20));
+    assert_eq!(balance(&deps, &accounts.sender), Uint128::new(999980));
+    assert!(query_stream(deps.as_ref(), mock_env(), 1).is_err());
+}
@@ -0,0 +1,310 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{Addr, Env, MessageInfo, OwnedDeps, Uint128};
+
+use crate::contract::{execute, instantiate, query_balance};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InitialBalance, InstantiateMsg, MinterResponse, VestingSchedule};
+use crate::vesting::query_vesting_info;
+
+// 테스트 상수 정의
+const CREATOR: &str = "cosmos1vlhe6z8r7al2lyzp7n3j2vl5kd28hhrw0vxmxr";
+const ADMIN: &str = "cosmos1wztmxhufhy98p3n45yqtwhrxlrr9wkg0tt3a3c";
+const INVEST: &str = "cosmos1qg9zllptnqvhyvrrvm0j3qjmtc5q6ds7eq0le4";
+const USER1: &str = "cosmos1fn9z9vn4k3qwr7vkg0yhzwv2q8h4lu4qsh7qv3";
+
+// start부터 100초 cliff, 1000초 뒤 전액 해제
+fn schedule(env: &Env) -> VestingSchedule {
+    VestingSchedule {
+        start: env.block.time,
+        cliff: env.block.time.plus_seconds(100),
+        end: env.block.time.plus_seconds(1000),
+    }
+}
+
+fn setup(env: &Env) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies();
+    let msg = InstantiateMsg {
+        name: "iUPPITER".to_string(),
+        symbol: "iUP".to_string(),
+        decimals: 6,
+        initial_balances: vec![
+            InitialBalance {
+                address: ADMIN.to_string(),
+                amount: Uint128::new(1000000),
+                vesting: None,
+            },
+            InitialBalance {
+                address: INVEST.to_string(),
+                amount: Uint128::new(100000),
+                vesting: Some(schedule(env)),
+            },
+        ],
+        marketing: None,
+        mint: Some(MinterResponse {
+            minter: ADMIN.to_string(),
+            cap: None,
+        }),
+        created_on_platform: None,
+    };
+    let info = MessageInfo {
+        sender: Addr::unchecked(CREATOR),
+        funds: vec![],
+    };
+    instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+    deps
+}
+
+fn transfer(amount: u128) -> ExecuteMsg {
+    ExecuteMsg::Transfer {
+        recipient: USER1.to_string(),
+        amount: Uint128::new(amount),
+    }
+}
+
+#[test]
+fn test_initial_balance_vesting() {
+    let mut env = mock_env();
+    let mut deps = setup(&env);
+    let invest_info = MessageInfo {
+        sender: Addr::unchecked(INVEST),
+        funds: vec![],
+    };
+
+    // cliff 이전에는 전액 잠김
+    let err = execute(deps.as_mut(), env.clone(), invest_info.clone(), transfer(1)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TokensLocked {
+            locked: Uint128::new(100000)
+        }
+    );
+
+    // 절반 경과: 50000 해제
+    env.block.time = env.block.time.plus_seconds(500);
+    let info = query_vesting_info(deps.as_ref(), env.clone(), INVEST.to_string()).unwrap();
+    assert_eq!(info.vested, Uint128::new(50000));
+    assert_eq!(info.locked, Uint128::new(50000));
+    assert_eq!(info.claimed, Uint128::zero());
+
+    let err = execute(deps.as_mut(), env.clone(), invest_info.clone(), transfer(50001)).unwrap_err();
+    assert!(matches!(err, ContractError::TokensLocked { .. }));
+    execute(deps.as_mut(), env.clone(), invest_info.clone(), transfer(30000)).unwrap();
+
+    let info = query_vesting_info(deps.as_ref(), env.clone(), INVEST.to_string()).unwrap();
+    assert_eq!(info.claimed, Uint128::new(30000));
+
+    // 추가로 받은 토큰은 잠금과 무관하게 사용 가능
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info,
+        ExecuteMsg::Transfer {
+            recipient: INVEST.to_string(),
+            amount: Uint128::new(10000),
+        },
+    )
+    .unwrap();
+    execute(deps.as_mut(), env.clone(), invest_info.clone(), transfer(30000)).unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), INVEST.to_string()).unwrap().balance,
+        Uint128::new(50000)
+    );
+    let info = query_vesting_info(deps.as_ref(), env.clone(), INVEST.to_string()).unwrap();
+    assert_eq!(info.claimed, Uint128::new(50000));
+
+    // 종료 후 전액 사용 가능
+    env.block.time = env.block.time.plus_seconds(500);
+    execute(deps.as_mut(), env.clone(), invest_info, transfer(50000)).unwrap();
+    let info = query_vesting_info(deps.as_ref(), env, INVEST.to_string()).unwrap();
+    assert_eq!(info.locked, Uint128::zero());
+    assert_eq!(info.claimed, Uint128::new(100000));
+}
+
+#[test]
+fn test_grant_vested() {
+    let env = mock_env();
+    let mut deps = setup(&env);
+    let admin_info = MessageInfo {
+        sender: Addr::unchecked(ADMIN),
+        funds: vec![],
+    };
+
+    // 관리자만 지급 가능
+    let grant = ExecuteMsg::GrantVested {
+        recipient: USER1.to_string(),
+        amount: Uint128::new(20000),
+        schedule: schedule(&env),
+        mint: false,
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        MessageInfo {
+            sender: Addr::unchecked(USER1),
+            funds: vec![],
+        },
+        grant.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // 관리자 잔액에서 이동
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), grant.clone()).unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), ADMIN.to_string()).unwrap().balance,
+        Uint128::new(980000)
+    );
+    let info = query_vesting_info(deps.as_ref(), env.clone(), USER1.to_string()).unwrap();
+    assert_eq!(info.total, Uint128::new(20000));
+    assert_eq!(info.locked, Uint128::new(20000));
+
+    // 같은 주소에 추가 지급하면 건별로 쌓임
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), grant).unwrap();
+    let info = query_vesting_info(deps.as_ref(), env.clone(), USER1.to_string()).unwrap();
+    assert_eq!(info.total, Uint128::new(40000));
+    assert_eq!(info.locked, Uint128::new(40000));
+    assert_eq!(info.grants.len(), 2);
+
+    // 초기 잔액으로 베스팅을 받은 주소에도 추가 지급 가능
+    let grant = ExecuteMsg::GrantVested {
+        recipient: INVEST.to_string(),
+        amount: Uint128::new(10000),
+        schedule: VestingSchedule {
+            start: env.block.time,
+            cliff: env.block.time,
+            end: env.block.time.plus_seconds(100),
+        },
+        mint: false,
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), grant).unwrap();
+    let info = query_vesting_info(deps.as_ref(), env.clone(), INVEST.to_string()).unwrap();
+    assert_eq!(info.total, Uint128::new(110000));
+    assert_eq!(info.grants[1].total, Uint128::new(10000));
+
+    // spender를 통한 출금도 잠금 적용
+    let user_info = MessageInfo {
+        sender: Addr::unchecked(USER1),
+        funds: vec![],
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        user_info,
+        ExecuteMsg::IncreaseAllowance {
+            spender: ADMIN.to_string(),
+            amount: Uint128::new(20000),
+            expires: None,
+            allowed_recipients: None,
+        },
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        ExecuteMsg::TransferFrom {
+            owner: USER1.to_string(),
+            recipient: ADMIN.to_string(),
+            amount: Uint128::new(1),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::TokensLocked { .. }));
+
+    // 신규 발행은 minter만 가능
+    let grant = ExecuteMsg::GrantVested {
+        recipient: CREATOR.to_string(),
+        amount: Uint128::new(5000),
+        schedule: schedule(&env),
+        mint: true,
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        MessageInfo {
+            sender: Addr::unchecked(USER1),
+            funds: vec![],
+        },
+        grant.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // 등록된 minter는 할당량 안에서만 발행
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info.clone(),
+        ExecuteMsg::SetMinter {
+            address: USER1.to_string(),
+            allowance: Uint128::new(1000),
+            expires: None,
+        },
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        MessageInfo {
+            sender: Addr::unchecked(USER1),
+            funds: vec![],
+        },
+        grant.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::MinterQuotaExceeded {
+            remaining: Uint128::new(1000)
+        }
+    );
+
+    // 신규 발행으로 지급
+    let grant = ExecuteMsg::GrantVested {
+        recipient: CREATOR.to_string(),
+        amount: Uint128::new(5000),
+        schedule: schedule(&env),
+        mint: true,
+    };
+    execute(deps.as_mut(), env.clone(), admin_info.clone(), grant).unwrap();
+    assert_eq!(
+        query_balance(deps.as_ref(), CREATOR.to_string()).unwrap().balance,
+        Uint128::new(5000)
+    );
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        MessageInfo {
+            sender: Addr::unchecked(CREATOR),
+            funds: vec![],
+        },
+        ExecuteMsg::Burn {
+            amount: Uint128::new(1),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::TokensLocked { .. }));
+
+    // 잘못된 스케줄 거부
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        admin_info,
+        ExecuteMsg::GrantVested {
+            recipient: INVEST.to_string(),
+            amount: Uint128::new(5000),
+            schedule: VestingSchedule {
+                start: env.block.time,
+                cliff: env.block.time.plus_seconds(2000),
+                end: env.block.time.plus_seconds(1000),
+            },
+            mint: true,
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidVestingSchedule { .. }));
+}
@@ -0,0 +1,197 @@
+use cosmwasm_std::{
+    Addr, BlockInfo, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Timestamp,
+    Uint128,
+};
+
+use crate::error::ContractError;
+use crate::freeze::assert_not_frozen;
+use crate::hooks::balance_change_hooks;
+use crate::limits::{assert_max_wallet, assert_transfer_limits};
+use crate::minters::authorize_mint;
+use crate::msg::{VestingGrantInfo, VestingInfoResponse, VestingSchedule};
+use crate::state::{VestingAccount, VestingGrant, BALANCES, EXTENDED_INFO, TOKEN_INFO, VESTING};
+use crate::supply::{assert_within_max_supply, record_total_supply};
+
+// 계정당 베스팅 지급 최대 건수
+pub const MAX_VESTING_GRANTS: u32 = 10;
+
+/// now 시점까지 풀린 양. cliff 이전 0, 이후 start 기준 선형, end 이후 전액
+pub fn vested_amount(grant: &VestingGrant, now: Timestamp) -> Uint128 {
+    let schedule = &grant.schedule;
+    if now < schedule.cliff {
+        Uint128::zero()
+    } else if now >= schedule.end {
+        grant.total
+    } else {
+        let elapsed = now.nanos() - schedule.start.nanos();
+        let duration = schedule.end.nanos() - schedule.start.nanos();
+        grant.total.multiply_ratio(elapsed, duration)
+    }
+}
+
+pub fn locked_amount(grant: &VestingGrant, now: Timestamp) -> Uint128 {
+    grant.total - vested_amount(grant, now)
+}
+
+/// 계정의 모든 지급 건 합계
+fn total_granted(account: &VestingAccount) -> Uint128 {
+    account.grants.iter().map(|grant| grant.total).sum()
+}
+
+fn total_locked(account: &VestingAccount, now: Timestamp) -> Uint128 {
+    account.grants.iter().map(|grant| locked_amount(grant, now)).sum()
+}
+
+/// address에 베스팅 지급 추가 (토큰 지급은 호출 측에서 처리)
+pub fn create_vesting(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    amount: Uint128,
+    schedule: VestingSchedule,
+) -> Result<(), ContractError> {
+    schedule.validate()?;
+    if amount.is_zero() {
+        return Err(ContractError::InvalidAmount {});
+    }
+    // 출금마다 전체 지급 건을 확인하므로 계정당 건수 제한
+    let mut account = VESTING.may_load(storage, address)?.unwrap_or(VestingAccount {
+        grants: vec![],
+        claimed: Uint128::zero(),
+    });
+    if account.grants.len() >= MAX_VESTING_GRANTS as usize {
+        return Err(ContractError::TooManyVestingGrants {
+            address: address.to_string(),
+            max: MAX_VESTING_GRANTS,
+        });
+    }
+    account.grants.push(VestingGrant {
+        total: amount,
+        schedule,
+    });
+    VESTING.save(storage, address, &account)?;
+    Ok(())
+}
+
+/// 출금 후 잔액이 잠긴 물량 아래로 내려가지 않는지 확인하고 claimed 갱신.
+/// 베스팅과 무관한 토큰이 먼저 빠져나간 것으로 본다
+pub fn assert_unlocked(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    address: &Addr,
+    new_balance: Uint128,
+) -> Result<(), ContractError> {
+    let Some(mut account) = VESTING.may_load(storage, address)? else {
+        return Ok(());
+    };
+    let locked = total_locked(&account, block.time);
+    if new_balance < locked {
+        return Err(ContractError::TokensLocked { locked });
+    }
+    let claimed = total_granted(&account).saturating_sub(new_balance);
+    if claimed > account.claimed {
+        account.claimed = claimed;
+        VESTING.save(storage, address, &account)?;
+    }
+    Ok(())
+}
+
+pub fn execute_grant_vested(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+    schedule: VestingSchedule,
+    mint: bool,
+) -> Result<Response, ContractError> {
+    if mint {
+        // 신규 발행은 Mint와 같은 권한, 할당량, 발행 속도 한도 적용
+        authorize_mint(deps.storage, &env.block, &info.sender, amount)?;
+    } else {
+        let extended_info = EXTENDED_INFO.load(deps.storage)?;
+        if info.sender != extended_info.admin {
+            return Err(ContractError::Unauthorized {});
+        }
+    }
+
+    #[cfg(test)]
+    let rcpt_addr = Addr::unchecked(&recipient);
+
+    #[cfg(not(test))]
+    let rcpt_addr = deps.api.addr_validate(&recipient)?;
+
+    assert_not_frozen(deps.storage, &[&info.sender, &rcpt_addr])?;
+    if mint {
+        assert_max_wallet(deps.storage, &rcpt_addr, amount)?;
+    } else {
+        assert_transfer_limits(deps.storage, &info.sender, &rcpt_addr, amount, amount)?;
+    }
+    create_vesting(deps.storage, &rcpt_addr, amount, schedule)?;
+
+    let mut changes = vec![];
+    if mint {
+        // 신규 발행 (최대 발행량 적용)
+        let mut config = TOKEN_INFO.load(deps.storage)?;
+        config.total_supply = config
+            .total_supply
+            .checked_add(amount)
+            .map_err(|_| ContractError::InvalidAmount {})?;
+        assert_within_max_supply(deps.storage, config.total_supply)?;
+        TOKEN_INFO.save(deps.storage, &config)?;
+        record_total_supply(deps.storage, env.block.height, config.total_supply)?;
+    } else {
+        // 관리자 잔액에서 이동 (관리자 본인의 잠긴 물량은 사용 불가)
+        let new_balance = BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
+            Ok(balance.unwrap_or_default().checked_sub(amount)?)
+        })?;
+        assert_unlocked(deps.storage, &env.block, &info.sender, new_balance)?;
+        changes.push((info.sender.clone(), new_balance + amount, new_balance));
+    }
+
+    let rcpt_balance = BALANCES.update(deps.storage, &rcpt_addr, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default() + amount)
+    })?;
+    changes.push((rcpt_addr, rcpt_balance - amount, rcpt_balance));
+    let hook_msgs = balance_change_hooks(deps.storage, changes)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "grant_vested")
+        .add_attribute("to", recipient)
+        .add_attribute("amount", amount)
+        .add_attribute("mint", mint.to_string())
+        .add_submessages(hook_msgs))
+}
+
+pub fn query_vesting_info(deps: Deps, env: Env, address: String) -> StdResult<VestingInfoResponse> {
+    #[cfg(test)]
+    let addr = Addr::unchecked(&address);
+    #[cfg(not(test))]
+    let addr = deps.api.addr_validate(&address)?;
+
+    let now = env.block.time;
+    let account = VESTING.may_load(deps.storage, &addr)?;
+    let grants: Vec<_> = account
+        .as_ref()
+        .map(|account| {
+            account
+                .grants
+                .iter()
+                .map(|grant| VestingGrantInfo {
+                    total: grant.total,
+                    vested: vested_amount(grant, now),
+                    schedule: grant.schedule.clone(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let total = grants.iter().map(|grant| grant.total).sum::<Uint128>();
+    let vested = grants.iter().map(|grant| grant.vested).sum::<Uint128>();
+    Ok(VestingInfoResponse {
+        address,
+        total,
+        vested,
+        locked: total - vested,
+        claimed: account.map(|account| account.claimed).unwrap_or_default(),
+        grants,
+    })
+}